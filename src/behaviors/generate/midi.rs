@@ -0,0 +1,275 @@
+use crate::types::pitch::chord::*;
+use num::rational::Ratio;
+
+/// A General MIDI instrument patch assignment.
+///
+/// ## Predicates
+///
+/// * `program` must be in `0..=127`.
+/// * `channel` must be in `0..=15`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct MidiInstrument {
+    pub program: u8,
+    pub channel: u8,
+}
+
+impl MidiInstrument {
+    pub fn new(program: u8, channel: u8) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            assert!(program <= 127, "MIDI program numbers in MidiInstrument must be in 0..=127.");
+            assert!(channel <= 15, "MIDI channels in MidiInstrument must be in 0..=15.");
+        }
+
+        Self { program, channel }
+    }
+}
+
+/// A melody with a duration, in beats, attached to each pitch.
+///
+/// ## Predicates
+///
+/// * `pitches` and `durations` must have the same length.
+/// * Pitches must be valid MIDI note numbers, in `0..=127`.
+/// * Durations must be positive.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TimedMelody {
+    pub pitches: Vec<i16>,
+    pub durations: Vec<Ratio<i32>>,
+    pub instrument: MidiInstrument,
+}
+
+impl TimedMelody {
+    pub fn new(pitches: Vec<i16>, durations: Vec<Ratio<i32>>, instrument: MidiInstrument) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(pitches.len(), durations.len(), "TimedMelody must have one duration per pitch.");
+            for &pitch in pitches.iter() {
+                assert!((0..=127).contains(&pitch), "Pitches in TimedMelody must be valid MIDI note numbers (0..=127).");
+            }
+            for &duration in durations.iter() {
+                assert!(duration > Ratio::from_integer(0), "Durations in TimedMelody must be positive.");
+            }
+        }
+
+        Self { pitches, durations, instrument }
+    }
+
+    /// Renders this melody as a format-0 standard MIDI file, its notes played one after
+    /// another at `tempo_bpm` beats per minute.
+    pub fn to_midi(&self, tempo_bpm: u32) -> Vec<u8> {
+        let mut events = tempo_meta_event(tempo_bpm);
+        events.extend(program_change_event(&self.instrument));
+
+        for (&pitch, &duration) in self.pitches.iter().zip(self.durations.iter()) {
+            let note = pitch as u8;
+            let duration_ticks = beats_to_ticks(duration, TICKS_PER_QUARTER);
+
+            events.extend(note_event(NOTE_ON, self.instrument.channel, note, DEFAULT_VELOCITY, 0));
+            events.extend(note_event(NOTE_OFF, self.instrument.channel, note, 0, duration_ticks));
+        }
+
+        let mut file = header_chunk(TICKS_PER_QUARTER);
+        file.extend(track_chunk(events));
+        file
+    }
+}
+
+/// A chord sequence with a duration, in beats, attached to each chord.
+///
+/// ## Predicates
+///
+/// * `chords` and `durations` must have the same length.
+/// * Durations must be positive.
+#[derive(PartialEq, Debug)]
+pub struct TimedChordSequence {
+    pub chords: Vec<Chord>,
+    pub durations: Vec<Ratio<i32>>,
+    pub instrument: MidiInstrument,
+}
+
+impl TimedChordSequence {
+    pub fn new(chords: Vec<Chord>, durations: Vec<Ratio<i32>>, instrument: MidiInstrument) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            assert_eq!(chords.len(), durations.len(), "TimedChordSequence must have one duration per chord.");
+            for &duration in durations.iter() {
+                assert!(duration > Ratio::from_integer(0), "Durations in TimedChordSequence must be positive.");
+            }
+        }
+
+        Self { chords, durations, instrument }
+    }
+
+    /// Renders this chord sequence as a format-0 standard MIDI file, each chord's pitches
+    /// sounding as simultaneous note-ons held for its duration at `tempo_bpm` beats per minute.
+    pub fn to_midi(&self, tempo_bpm: u32) -> Vec<u8> {
+        let mut events = tempo_meta_event(tempo_bpm);
+        events.extend(program_change_event(&self.instrument));
+
+        for (chord, &duration) in self.chords.iter().zip(self.durations.iter()) {
+            let duration_ticks = beats_to_ticks(duration, TICKS_PER_QUARTER);
+
+            for &pitch in chord.pitches.iter() {
+                events.extend(note_event(NOTE_ON, self.instrument.channel, pitch as u8, DEFAULT_VELOCITY, 0));
+            }
+
+            for (i, &pitch) in chord.pitches.iter().enumerate() {
+                let delta = if i == 0 { duration_ticks } else { 0 };
+                events.extend(note_event(NOTE_OFF, self.instrument.channel, pitch as u8, 0, delta));
+            }
+        }
+
+        let mut file = header_chunk(TICKS_PER_QUARTER);
+        file.extend(track_chunk(events));
+        file
+    }
+}
+
+/// MIDI ticks per quarter note, used as this module's fixed time-division for exported files.
+/// Independent of the crate's own `Ticks::RESOLUTION`, which governs the internal rhythm model.
+const TICKS_PER_QUARTER: u16 = 480;
+
+const DEFAULT_VELOCITY: u8 = 64;
+
+const NOTE_ON: u8 = 0x90;
+const NOTE_OFF: u8 = 0x80;
+
+/// Converts a duration in beats (quarter notes) to the nearest whole number of MIDI ticks.
+fn beats_to_ticks(duration: Ratio<i32>, ticks_per_quarter: u16) -> u32 {
+    (duration * Ratio::from_integer(ticks_per_quarter as i32))
+        .round()
+        .to_integer()
+        .max(0) as u32
+}
+
+/// Encodes `value` as a MIDI variable-length quantity and appends it to `buf`.
+fn write_vlq(buf: &mut Vec<u8>, value: u32) {
+    let mut septets = vec![(value & 0x7F) as u8];
+    let mut remainder = value >> 7;
+    while remainder > 0 {
+        septets.push(((remainder & 0x7F) as u8) | 0x80);
+        remainder >>= 7;
+    }
+    septets.reverse();
+    buf.extend_from_slice(&septets);
+}
+
+/// Builds a single note-on/note-off event, preceded by its delta-time.
+fn note_event(status: u8, channel: u8, note: u8, velocity: u8, delta: u32) -> Vec<u8> {
+    let mut event = vec![];
+    write_vlq(&mut event, delta);
+    event.push(status | channel);
+    event.push(note);
+    event.push(velocity);
+    event
+}
+
+/// A set-tempo meta event at time zero, in microseconds per quarter note.
+fn tempo_meta_event(tempo_bpm: u32) -> Vec<u8> {
+    let micros_per_quarter = (60_000_000 / tempo_bpm).to_be_bytes();
+    vec![0x00, 0xFF, 0x51, 0x03, micros_per_quarter[1], micros_per_quarter[2], micros_per_quarter[3]]
+}
+
+/// A program-change event at time zero, assigning the track its `MidiInstrument` patch.
+fn program_change_event(instrument: &MidiInstrument) -> Vec<u8> {
+    vec![0x00, 0xC0 | instrument.channel, instrument.program]
+}
+
+/// The `MThd` header chunk for a format-0, single-track standard MIDI file.
+fn header_chunk(ticks_per_quarter: u16) -> Vec<u8> {
+    let mut chunk = b"MThd".to_vec();
+    chunk.extend_from_slice(&6u32.to_be_bytes());
+    chunk.extend_from_slice(&0u16.to_be_bytes());
+    chunk.extend_from_slice(&1u16.to_be_bytes());
+    chunk.extend_from_slice(&ticks_per_quarter.to_be_bytes());
+    chunk
+}
+
+/// Wraps `events` in an `MTrk` track chunk, appending the mandatory end-of-track meta event.
+fn track_chunk(mut events: Vec<u8>) -> Vec<u8> {
+    events.extend_from_slice(&[0x00, 0xFF, 0x2F, 0x00]);
+
+    let mut chunk = b"MTrk".to_vec();
+    chunk.extend_from_slice(&(events.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(&events);
+    chunk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_timed_melody_mismatched_lengths() {
+        let instrument = MidiInstrument::new(0, 0);
+        TimedMelody::new(vec![60, 62], vec![Ratio::from_integer(1)], instrument);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_timed_melody_out_of_range_pitch() {
+        let instrument = MidiInstrument::new(0, 0);
+        TimedMelody::new(vec![128], vec![Ratio::from_integer(1)], instrument);
+    }
+
+    #[test]
+    fn test_beats_to_ticks() {
+        assert_eq!(beats_to_ticks(Ratio::new(1, 2), TICKS_PER_QUARTER), 240);
+        assert_eq!(beats_to_ticks(Ratio::new(1, 3), TICKS_PER_QUARTER), 160);
+    }
+
+    #[test]
+    fn test_write_vlq() {
+        let mut buf = vec![];
+        write_vlq(&mut buf, 0);
+        assert_eq!(buf, vec![0x00]);
+
+        let mut buf = vec![];
+        write_vlq(&mut buf, 127);
+        assert_eq!(buf, vec![0x7F]);
+
+        let mut buf = vec![];
+        write_vlq(&mut buf, 128);
+        assert_eq!(buf, vec![0x81, 0x00]);
+    }
+
+    #[test]
+    fn test_timed_melody_to_midi_starts_with_header_and_tempo() {
+        let instrument = MidiInstrument::new(0, 0);
+        let melody = TimedMelody::new(
+            vec![60, 64, 67],
+            vec![Ratio::from_integer(1), Ratio::from_integer(1), Ratio::from_integer(1)],
+            instrument,
+        );
+
+        let bytes = melody.to_midi(120);
+
+        assert_eq!(&bytes[0..4], b"MThd");
+        assert_eq!(&bytes[14..18], b"MTrk");
+        assert_eq!(&bytes[22..26], &[0x00, 0xFF, 0x51, 0x03]);
+        assert_eq!(&bytes[bytes.len() - 4..], &[0x00, 0xFF, 0x2F, 0x00]);
+    }
+
+    #[test]
+    fn test_timed_chord_sequence_to_midi_emits_simultaneous_note_ons() {
+        let instrument = MidiInstrument::new(0, 0);
+        let chord = Chord::new(vec![60, 64, 67]);
+        let sequence = TimedChordSequence::new(
+            vec![chord],
+            vec![Ratio::from_integer(1)],
+            instrument,
+        );
+
+        let bytes = sequence.to_midi(120);
+        // Track data starts at 22, after the tempo meta event (7 bytes) and program change (3 bytes).
+        let notes_start = 22 + 7 + 3;
+
+        // Three back-to-back zero-delta note-ons: [0x00, 0x90, note, velocity] each.
+        for (i, &note) in [60u8, 64, 67].iter().enumerate() {
+            let event_start = notes_start + i * 4;
+            assert_eq!(&bytes[event_start..event_start + 4], &[0x00, 0x90, note, DEFAULT_VELOCITY]);
+        }
+    }
+}
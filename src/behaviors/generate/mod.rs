@@ -1,12 +1,32 @@
 use crate::types::scale::*;
+use crate::types::pitch::chord::*;
+use crate::types::progression::*;
 use crate::behaviors::analyze::*;
 use num::integer::gcd;
 
 /// Search Module
-/// 
+///
 /// The `search` module provides tools for searching for objects that meet provided criteria.
 pub mod search;
 
+/// Voicing Module
+///
+/// The `voicing` module provides tools for realizing chords as concrete fingerings on fretted,
+/// tuned instruments.
+pub mod voicing;
+
+/// Rhythm Module
+///
+/// The `rhythm` module provides a nested, repeatable rhythm-pattern builder that flattens
+/// into the flat `TimeSet`/`TimeClassSet` types the rest of the crate consumes.
+pub mod rhythm;
+
+/// MIDI Module
+///
+/// The `midi` module pairs melodies and chord sequences with beat durations and an instrument,
+/// and renders the result to a standard MIDI file for audition.
+pub mod midi;
+
 impl ScaleMap {
     /// Composes two scale maps.
     /// 
@@ -43,6 +63,273 @@ impl ScaleMap {
     }
 }
 
+impl Scale {
+    /// Generates the maximally even distribution of `k` pitch classes in a modulus-`n` universe.
+    ///
+    /// Uses the closed form `pc[i] = (i * modulus).div_euclid(k)`. This yields a rotation of
+    /// the diatonic scale for `(7,12)`, the pentatonic scale for `(5,12)`, and generalizes to
+    /// any microtonal modulus.
+    pub fn maximally_even(k: i16, modulus: i16) -> Self {
+        let pitch_classes: Vec<i16> = (0..k).map(|i| (i * modulus).div_euclid(k)).collect();
+
+        Self::new(pitch_classes, modulus)
+    }
+}
+
+impl ScaleShape {
+    /// The step pattern of the maximally even distribution of `k` degrees in a modulus-`n` universe.
+    pub fn maximally_even(k: i16, modulus: i16) -> Self {
+        Scale::maximally_even(k, modulus).shape()
+    }
+}
+
+pub mod named_scales {
+    use super::*;
+    use crate::behaviors::transform::Rotate;
+
+    impl Scale {
+        /// Builds a scale from a step pattern, accumulating each step (all but the last, which
+        /// closes the octave) into an ascending residue class starting at 0.
+        ///
+        /// e.g. `[2,2,1,2,2,2,1]` in modulus 12 accumulates to `[0,2,4,5,7,9,11]`, the major scale.
+        pub fn from_steps(steps: &[i16], modulus: i16) -> Self {
+            let pitch_classes: Vec<i16> = std::iter::once(0)
+                .chain(steps[..steps.len() - 1].iter().scan(0, |acc, &step| {
+                    *acc += step;
+                    Some(*acc)
+                }))
+                .collect();
+
+            Self::new(pitch_classes, modulus)
+        }
+
+        /// The major (Ionian) scale.
+        pub fn major() -> Self {
+            Self::from_steps(&[2,2,1,2,2,2,1], 12)
+        }
+
+        /// The Dorian mode.
+        pub fn dorian() -> Self {
+            Self::from_steps(&[2,1,2,2,2,1,2], 12)
+        }
+
+        /// The Phrygian mode.
+        pub fn phrygian() -> Self {
+            Self::from_steps(&[1,2,2,2,1,2,2], 12)
+        }
+
+        /// The Lydian mode.
+        pub fn lydian() -> Self {
+            Self::from_steps(&[2,2,2,1,2,2,1], 12)
+        }
+
+        /// The Mixolydian mode.
+        pub fn mixolydian() -> Self {
+            Self::from_steps(&[2,2,1,2,2,1,2], 12)
+        }
+
+        /// The Aeolian mode (natural minor scale).
+        pub fn natural_minor() -> Self {
+            Self::from_steps(&[2,1,2,2,1,2,2], 12)
+        }
+
+        /// The Locrian mode.
+        pub fn locrian() -> Self {
+            Self::from_steps(&[1,2,2,1,2,2,2], 12)
+        }
+
+        /// The harmonic minor scale.
+        pub fn harmonic_minor() -> Self {
+            Self::from_steps(&[2,1,2,2,1,3,1], 12)
+        }
+
+        /// The ascending melodic minor scale.
+        pub fn melodic_minor() -> Self {
+            Self::from_steps(&[2,1,2,2,2,2,1], 12)
+        }
+
+        /// The major pentatonic scale.
+        pub fn major_pentatonic() -> Self {
+            Self::from_steps(&[2,2,3,2,3], 12)
+        }
+
+        /// The whole-tone scale.
+        pub fn whole_tone() -> Self {
+            Self::from_steps(&[2,2,2,2,2,2], 12)
+        }
+
+        /// The octatonic (whole-half diminished) scale.
+        pub fn octatonic() -> Self {
+            Self::from_steps(&[2,1,2,1,2,1,2,1], 12)
+        }
+
+        /// Every rotation of the scale's step pattern, each re-stamped at its corresponding
+        /// scale degree — the classic "modes of a scale" relationship.
+        ///
+        /// Unlike `Modes::modes`, which yields only the scale's `count_modes()` *distinct*
+        /// rotations, this returns one entry per degree, including repeats for periodic shapes
+        /// like the whole-tone or octatonic scales. Named `church_modes` (rather than `modes`)
+        /// so it doesn't shadow the `Modes` trait's inherent-method-shadowing trap.
+        pub fn church_modes(&self) -> Vec<Scale> {
+            let shape = self.shape();
+
+            (0..self.len())
+                .map(|i| {
+                    let root = self.pitch_classes[i];
+                    shape.rotate(i as i16).stamp(root)
+                })
+                .collect()
+        }
+    }
+}
+
+impl ScaleKey {
+    /// Builds the chord rooted on a scale degree by stacking scale members `step` degrees apart.
+    ///
+    /// Selects every `step`-th degree starting at `degree` (`degree, degree+step, degree+2*step, …`)
+    /// for `degrees_per_chord` notes, wrapping with `rem_euclid` and adding an octave of `modulus`
+    /// for each time the index wraps past the end of the scale.
+    pub fn degree_chord(&self, degree: usize, degrees_per_chord: usize, step: usize) -> Chord {
+        let len = self.len();
+        let modulus = self.modulus;
+
+        let mut pitches: Vec<i16> = (0..degrees_per_chord)
+            .map(|i| {
+                let idx = degree + i * step;
+                let oct = (idx / len) as i16;
+                let pc = self.pitch_classes[idx % len];
+                oct * modulus + pc
+            })
+            .collect();
+        pitches.sort();
+
+        Chord::new(pitches)
+    }
+
+    /// Harmonizes the scale by stacking scale members on every degree.
+    ///
+    /// Returns one chord of `degrees_per_chord` notes per scale degree, e.g. the diatonic
+    /// triads (`degrees_per_chord == 3, step == 2`) or seventh chords (`degrees_per_chord == 4, step == 2`)
+    /// of the scale.
+    pub fn harmonize(&self, degrees_per_chord: usize, step: usize) -> ChordSequence {
+        let chords = (0..self.len())
+            .map(|degree| self.degree_chord(degree, degrees_per_chord, step))
+            .collect();
+
+        ChordSequence::new(chords)
+    }
+}
+
+impl Scale {
+    /// Builds the chord rooted on a scale degree by stacking scale members `step` degrees apart.
+    ///
+    /// Mirrors `ScaleKey::degree_chord`, but over a `Scale`'s unrooted pitch class set.
+    pub fn degree_chord(&self, degree: usize, degrees_per_chord: usize, step: usize) -> Chord {
+        let len = self.len();
+        let modulus = self.modulus;
+
+        let mut pitches: Vec<i16> = (0..degrees_per_chord)
+            .map(|i| {
+                let idx = degree + i * step;
+                let oct = (idx / len) as i16;
+                let pc = self.pitch_classes[idx % len];
+                oct * modulus + pc
+            })
+            .collect();
+        pitches.sort();
+
+        Chord::new(pitches)
+    }
+
+    /// Harmonizes the scale by stacking scale members on every degree.
+    ///
+    /// Returns one chord of `degrees_per_chord` notes per scale degree, e.g. the diatonic
+    /// triads (`degrees_per_chord == 3, step == 2`) of the scale.
+    pub fn harmonize(&self, degrees_per_chord: usize, step: usize) -> ChordSequence {
+        let chords = (0..self.len())
+            .map(|degree| self.degree_chord(degree, degrees_per_chord, step))
+            .collect();
+
+        ChordSequence::new(chords)
+    }
+}
+
+pub mod pitch_harmonize {
+    use super::*;
+    use crate::types::pitch::{scale::{PitchScaleKey, PitchScaleMap}, chord::Chord};
+
+    impl PitchScaleKey {
+        /// Builds the chord rooted on a scale degree by stacking every other degree, wrapping
+        /// octaves via `eval`.
+        fn degree_chord(&self, degree: i16, degrees_per_chord: i16) -> Chord {
+            let mut pitches: Vec<i16> = (0..degrees_per_chord)
+                .map(|i| self.eval(degree + i * 2))
+                .collect();
+            pitches.sort();
+
+            Chord::new(pitches)
+        }
+
+        /// The triads rooted on every scale degree, stacking every other degree
+        /// (`eval(d), eval(d+2), eval(d+4)`).
+        pub fn triads(&self) -> Vec<Chord> {
+            (0..self.len() as i16).map(|degree| self.degree_chord(degree, 3)).collect()
+        }
+
+        /// The seventh chords rooted on every scale degree, stacking every other degree
+        /// (`eval(d), eval(d+2), eval(d+4), eval(d+6)`).
+        pub fn tetrads(&self) -> Vec<Chord> {
+            (0..self.len() as i16).map(|degree| self.degree_chord(degree, 4)).collect()
+        }
+    }
+
+    impl PitchScaleMap {
+        /// Builds the chord rooted on a scale degree by stacking every other degree, wrapping
+        /// octaves via `eval`.
+        fn degree_chord(&self, degree: i16, degrees_per_chord: i16) -> Chord {
+            let mut pitches: Vec<i16> = (0..degrees_per_chord)
+                .map(|i| self.eval(degree + i * 2))
+                .collect();
+            pitches.sort();
+
+            Chord::new(pitches)
+        }
+
+        /// The triads rooted on every scale degree, stacking every other degree
+        /// (`eval(d), eval(d+2), eval(d+4)`).
+        pub fn triads(&self) -> Vec<Chord> {
+            (0..self.len() as i16).map(|degree| self.degree_chord(degree, 3)).collect()
+        }
+
+        /// The seventh chords rooted on every scale degree, stacking every other degree
+        /// (`eval(d), eval(d+2), eval(d+4), eval(d+6)`).
+        pub fn tetrads(&self) -> Vec<Chord> {
+            (0..self.len() as i16).map(|degree| self.degree_chord(degree, 4)).collect()
+        }
+    }
+}
+
+impl ScaleMap {
+    /// Harmonizes the scale map by stacking scale members on every degree.
+    ///
+    /// Unlike `Scale`/`ScaleKey`, this builds each chord via `eval`, which already carries
+    /// the quotient/remainder octave arithmetic needed when `degree + i * step` runs past `len()`.
+    pub fn harmonize(&self, degrees_per_chord: usize, step: usize) -> ChordSequence {
+        let chords = (0..self.len())
+            .map(|degree| {
+                let mut pitches: Vec<i16> = (0..degrees_per_chord)
+                    .map(|i| Eval::<i16>::eval(self, (degree + i * step) as i16))
+                    .collect();
+                pitches.sort();
+
+                Chord::new(pitches)
+            })
+            .collect();
+
+        ChordSequence::new(chords)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +342,156 @@ mod tests {
 
         assert_eq!(scale_map1.compose(&scale_map2), result);
     }
+
+    #[test]
+    fn test_maximally_even_scale() {
+        let scale = Scale::maximally_even(5, 12);
+        let result = Scale::new(vec![0,2,4,7,9], 12);
+
+        assert_eq!(scale, result);
+    }
+
+    #[test]
+    fn test_maximally_even_scale_shape() {
+        let shape = ScaleShape::maximally_even(7, 12);
+        let result = ScaleShape::new(vec![1,2,2,1,2,2,2]);
+
+        assert_eq!(shape, result);
+    }
+
+    #[test]
+    fn test_degree_chord() {
+        let scale_key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let result = Chord::new(vec![0,4,7]);
+
+        assert_eq!(scale_key.degree_chord(0, 3, 2), result);
+    }
+
+    #[test]
+    fn test_harmonize() {
+        let scale_key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let harmonization = scale_key.harmonize(3, 2);
+
+        assert_eq!(harmonization.chords[0], Chord::new(vec![0,4,7]));
+        assert_eq!(harmonization.chords[1], Chord::new(vec![2,5,9]));
+    }
+
+    #[test]
+    fn test_harmonize_scale() {
+        let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+        let harmonization = scale.harmonize(3, 2);
+
+        assert_eq!(harmonization.chords[0], Chord::new(vec![0,4,7]));
+        assert_eq!(harmonization.chords[1], Chord::new(vec![2,5,9]));
+    }
+
+    #[test]
+    fn test_harmonize_scale_map() {
+        let scale_map = ScaleMap::new(vec![2,4,5,7,9,11,12], 0);
+        let harmonization = scale_map.harmonize(3, 2);
+
+        assert_eq!(harmonization.chords[0], Chord::new(vec![0,4,7]));
+        assert_eq!(harmonization.chords[1], Chord::new(vec![2,5,9]));
+    }
+
+    #[test]
+    fn test_harmonize_sevenths() {
+        let scale_key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let harmonization = scale_key.harmonize(4, 2);
+
+        assert_eq!(harmonization.chords[0], Chord::new(vec![0,4,7,11]));
+    }
+
+    mod pitch_harmonize {
+        use crate::types::pitch::{scale::{PitchScaleKey, PitchScaleMap}, chord::Chord};
+
+        #[test]
+        fn test_triads() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let triads = key.triads();
+
+            assert_eq!(triads[0], Chord::new(vec![0,4,7]));
+            assert_eq!(triads[1], Chord::new(vec![2,5,9]));
+        }
+
+        #[test]
+        fn test_tetrads() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let tetrads = key.tetrads();
+
+            assert_eq!(tetrads[0], Chord::new(vec![0,4,7,11]));
+        }
+
+        #[test]
+        fn test_triads_wrap_octave() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let triads = key.triads();
+
+            // vii° wraps past the top of the scale: eval(6), eval(8), eval(10).
+            assert_eq!(triads[6], Chord::new(vec![11,14,17]));
+        }
+
+        #[test]
+        fn test_scale_map_triads() {
+            let scale_map = PitchScaleMap::new(vec![2,4,5,7,9,11,12], 0);
+            let triads = scale_map.triads();
+
+            assert_eq!(triads[0], Chord::new(vec![0,4,7]));
+            assert_eq!(triads[1], Chord::new(vec![2,5,9]));
+        }
+    }
+
+    mod named_scales {
+        use super::*;
+
+        #[test]
+        fn test_from_steps() {
+            let major = Scale::from_steps(&[2,2,1,2,2,2,1], 12);
+            let result = Scale::new(vec![0,2,4,5,7,9,11], 12);
+
+            assert_eq!(major, result);
+        }
+
+        #[test]
+        fn test_major() {
+            assert_eq!(Scale::major(), Scale::new(vec![0,2,4,5,7,9,11], 12));
+        }
+
+        #[test]
+        fn test_dorian() {
+            assert_eq!(Scale::dorian(), Scale::new(vec![0,2,3,5,7,9,10], 12));
+        }
+
+        #[test]
+        fn test_harmonic_minor() {
+            assert_eq!(Scale::harmonic_minor(), Scale::new(vec![0,2,3,5,7,8,11], 12));
+        }
+
+        #[test]
+        fn test_whole_tone() {
+            assert_eq!(Scale::whole_tone(), Scale::new(vec![0,2,4,6,8,10], 12));
+        }
+
+        #[test]
+        fn test_octatonic() {
+            assert_eq!(Scale::octatonic(), Scale::new(vec![0,2,3,5,6,8,9,11], 12));
+        }
+
+        #[test]
+        fn test_modes_count_matches_degree_count() {
+            let major = Scale::major();
+
+            assert_eq!(major.church_modes().len(), major.len());
+        }
+
+        #[test]
+        fn test_modes_one_per_degree_even_when_periodic() {
+            // The octatonic scale has only 2 distinct modes (`count_modes()`), but
+            // `church_modes()` still returns one entry per degree (8), unlike the
+            // deduplicating `Modes` trait.
+            let octatonic = Scale::octatonic();
+
+            assert_eq!(octatonic.church_modes().len(), 8);
+        }
+    }
 }
\ No newline at end of file
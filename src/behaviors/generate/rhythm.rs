@@ -0,0 +1,111 @@
+use crate::types::rhythm::*;
+
+impl RhythmGroup {
+    /// Flattens the nested pattern into onset times, expressed as a `TimeSet`.
+    ///
+    /// Walks the tree in order: each `Note` records an onset at the current cursor before
+    /// advancing it by the note's duration, each `Rest` just advances the cursor as a gap,
+    /// and each nested `Group` is expanded in place, its own `repeats` applying only to
+    /// itself. The whole pattern is then repeated `self.repeats` times in sequence.
+    pub fn flatten(&self) -> TimeSet {
+        let mut onsets = vec![];
+        let mut cursor = Ticks(0);
+
+        self.flatten_onto(&mut onsets, &mut cursor);
+
+        TimeSet::new(onsets)
+    }
+
+    fn flatten_onto(&self, onsets: &mut Vec<Ticks>, cursor: &mut Ticks) {
+        for _ in 0..self.repeats {
+            for item in &self.items {
+                match item {
+                    RhythmItem::Note(duration) => {
+                        onsets.push(*cursor);
+                        *cursor = *cursor + *duration;
+                    }
+                    RhythmItem::Rest(duration) => {
+                        *cursor = *cursor + *duration;
+                    }
+                    RhythmItem::Group(group) => {
+                        group.flatten_onto(onsets, cursor);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The pattern's total duration in ticks, summing every expanded note and rest.
+    pub fn to_ticks(&self) -> Ticks {
+        let total = self.items.iter().fold(Ticks(0), |acc, item| acc + match item {
+            RhythmItem::Note(duration) | RhythmItem::Rest(duration) => *duration,
+            RhythmItem::Group(group) => group.to_ticks()
+        });
+
+        total * self.repeats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_flat_pattern() {
+        let pattern = RhythmGroup::new(vec![
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Rest(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+        ], 1);
+
+        let result = TimeSet::new(vec![Ticks(0), Ticks(96)]);
+
+        assert_eq!(pattern.flatten(), result);
+    }
+
+    #[test]
+    fn test_flatten_repeated_group() {
+        // [(quarter, quarter, eighth) x2, half]
+        let inner = RhythmGroup::new(vec![
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Note(BasicLength::Eighth.to_ticks()),
+        ], 2);
+
+        let pattern = RhythmGroup::new(vec![
+            RhythmItem::Group(inner),
+            RhythmItem::Note(BasicLength::Half.to_ticks()),
+        ], 1);
+
+        let result = TimeSet::new(vec![
+            Ticks(0), Ticks(48), Ticks(96), Ticks(120), Ticks(168), Ticks(216), Ticks(240)
+        ]);
+
+        assert_eq!(pattern.flatten(), result);
+    }
+
+    #[test]
+    fn test_to_ticks_repeated_group() {
+        let inner = RhythmGroup::new(vec![
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+            RhythmItem::Note(BasicLength::Eighth.to_ticks()),
+        ], 2);
+
+        let pattern = RhythmGroup::new(vec![
+            RhythmItem::Group(inner),
+            RhythmItem::Note(BasicLength::Half.to_ticks()),
+        ], 1);
+
+        assert_eq!(pattern.to_ticks(), Ticks(336));
+    }
+
+    #[test]
+    fn test_to_ticks_outer_repeat_multiplies_whole_pattern() {
+        let pattern = RhythmGroup::new(vec![
+            RhythmItem::Note(BasicLength::Quarter.to_ticks()),
+        ], 3);
+
+        assert_eq!(pattern.to_ticks(), Ticks(144));
+    }
+}
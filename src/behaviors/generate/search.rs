@@ -1,4 +1,4 @@
-use crate::types::{chord::*, melody::*, scale::*, progression::*};
+use crate::types::{pitch::chord::*, melody::*, scale::*, progression::*};
 use crate::behaviors::analyze::*;
 use itertools::Itertools;
 
@@ -24,10 +24,420 @@ pub mod chord {
     }
 }
 
+pub mod progression {
+    use super::*;
+    use std::collections::BinaryHeap;
+    use std::cmp::Ordering;
+
+    /// A weighted scoring rule applied to a candidate chord in the context of the sequence
+    /// chosen so far.
+    ///
+    /// `score` returns the candidate's raw, unweighted contribution to the running total;
+    /// `weight` scales it before it's added.
+    pub struct MusicType {
+        pub weight: f64,
+        pub score: Box<dyn Fn(&[Chord], &Chord) -> f64>,
+    }
+
+    impl MusicType {
+        pub fn new(weight: f64, score: impl Fn(&[Chord], &Chord) -> f64 + 'static) -> Self {
+            Self { weight, score: Box::new(score) }
+        }
+    }
+
+    /// Rewards a candidate chord for how many of its pairwise interval classes are in `targets`.
+    pub fn reward_interval_classes(targets: Vec<i16>, modulus: i16) -> impl Fn(&[Chord], &Chord) -> f64 {
+        move |_, candidate| {
+            let pitches = &candidate.pitches;
+            let mut hits = 0;
+
+            for i in 0..pitches.len() {
+                for j in (i + 1)..pitches.len() {
+                    let interval_class = (pitches[j] - pitches[i]).rem_euclid(modulus);
+                    if targets.contains(&interval_class) {
+                        hits += 1;
+                    }
+                }
+            }
+
+            hits as f64
+        }
+    }
+
+    /// Penalizes a candidate chord for containing any pitch class in `forbidden`.
+    pub fn penalize_forbidden_pitch_classes(forbidden: Vec<i16>, modulus: i16) -> impl Fn(&[Chord], &Chord) -> f64 {
+        move |_, candidate| {
+            let hits = candidate.pitches.iter()
+                .filter(|&&pitch| forbidden.contains(&pitch.rem_euclid(modulus)))
+                .count();
+
+            -(hits as f64)
+        }
+    }
+
+    /// Rewards voice-leading smoothness: the negative of the total absolute semitone motion
+    /// from the previous chord to the candidate, so smoother motion scores closer to zero.
+    pub fn voice_leading_smoothness(prefix: &[Chord], candidate: &Chord) -> f64 {
+        match prefix.last() {
+            Some(previous) => {
+                let motion: i16 = previous.pitches.iter().zip(candidate.pitches.iter())
+                    .map(|(&a, &b)| (a - b).abs())
+                    .sum();
+
+                -(motion as f64)
+            }
+            None => 0.0
+        }
+    }
+
+    struct ScoredPartial {
+        score: f64,
+        chords: Vec<Chord>,
+    }
+
+    impl PartialEq for ScoredPartial {
+        fn eq(&self, other: &Self) -> bool {
+            self.score == other.score
+        }
+    }
+
+    impl Eq for ScoredPartial {}
+
+    impl PartialOrd for ScoredPartial {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.score.partial_cmp(&other.score)
+        }
+    }
+
+    impl Ord for ScoredPartial {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        }
+    }
+
+    /// A scored, constraint-driven search over chord sequences.
+    ///
+    /// Takes one candidate pool per step of the sequence plus a set of weighted `MusicType`s,
+    /// and explores the combined space with a bounded best-first search: at each step, a
+    /// `BinaryHeap` frontier keyed by partial score keeps only the `beam_width` most
+    /// promising partial sequences alive to extend, so a proximity window that would blow up
+    /// a full Cartesian product stays tractable.
+    pub struct ProgressionSearch {
+        steps: Vec<Vec<Chord>>,
+        music_types: Vec<MusicType>,
+        num_results: usize,
+        unique: bool,
+        beam_width: usize,
+    }
+
+    impl ProgressionSearch {
+        pub fn new(steps: Vec<Vec<Chord>>) -> Self {
+            Self {
+                steps,
+                music_types: Vec::new(),
+                num_results: 10,
+                unique: false,
+                beam_width: 64,
+            }
+        }
+
+        /// Adds a weighted scoring rule.
+        pub fn music_type(mut self, music_type: MusicType) -> Self {
+            self.music_types.push(music_type);
+            self
+        }
+
+        /// Caps how many ranked sequences `search` returns.
+        pub fn num_results(mut self, num_results: usize) -> Self {
+            self.num_results = num_results;
+            self
+        }
+
+        /// Rejects sequences that repeat the same chord at more than one step.
+        pub fn unique(mut self, unique: bool) -> Self {
+            self.unique = unique;
+            self
+        }
+
+        /// Caps how many partial sequences survive each step of the search.
+        pub fn beam_width(mut self, beam_width: usize) -> Self {
+            self.beam_width = beam_width;
+            self
+        }
+
+        fn candidate_score(&self, prefix: &[Chord], candidate: &Chord) -> f64 {
+            self.music_types.iter()
+                .map(|music_type| music_type.weight * (music_type.score)(prefix, candidate))
+                .sum()
+        }
+
+        /// Runs the search, returning up to `num_results` chord sequences ranked by summed
+        /// score, highest first.
+        pub fn search(&self) -> Vec<ChordSequence> {
+            let mut frontier = vec![ScoredPartial { score: 0.0, chords: Vec::new() }];
+
+            for candidates in &self.steps {
+                let mut next_frontier: BinaryHeap<ScoredPartial> = BinaryHeap::new();
+
+                for partial in &frontier {
+                    for candidate in candidates {
+                        if self.unique && partial.chords.iter().any(|chord| chord == candidate) {
+                            continue;
+                        }
+
+                        let score = partial.score + self.candidate_score(&partial.chords, candidate);
+                        let mut chords: Vec<Chord> = partial.chords.iter()
+                            .map(|chord| Chord::new(chord.pitches.clone()))
+                            .collect();
+                        chords.push(Chord::new(candidate.pitches.clone()));
+
+                        next_frontier.push(ScoredPartial { score, chords });
+                    }
+                }
+
+                frontier = next_frontier.into_sorted_vec();
+                frontier.reverse();
+                frontier.truncate(self.beam_width);
+            }
+
+            frontier.truncate(self.num_results);
+
+            frontier.into_iter().map(|partial| ChordSequence::new(partial.chords)).collect()
+        }
+    }
+}
+
+pub mod scale_shape {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// A builder-style combinatorial search over `ScaleShape`s of a given modulus and cardinality.
+    ///
+    /// Streams its results lazily via `search`, since the space of interval compositions
+    /// grows quickly with the modulus.
+    pub struct ScaleShapeSearch {
+        modulus: i16,
+        cardinality: usize,
+        min_interval: i16,
+        max_interval: i16,
+        required_subpattern: Option<Vec<i16>>,
+        target_transpositions: Option<usize>,
+        unique_up_to_rotation: bool,
+    }
+
+    impl ScaleShapeSearch {
+        pub fn new(modulus: i16, cardinality: usize) -> Self {
+            Self {
+                modulus,
+                cardinality,
+                min_interval: 1,
+                max_interval: modulus,
+                required_subpattern: None,
+                target_transpositions: None,
+                unique_up_to_rotation: false,
+            }
+        }
+
+        /// Sets the minimum interval allowed between adjacent degrees.
+        pub fn min_interval(mut self, min_interval: i16) -> Self {
+            self.min_interval = min_interval;
+            self
+        }
+
+        /// Sets the maximum interval allowed between adjacent degrees.
+        pub fn max_interval(mut self, max_interval: i16) -> Self {
+            self.max_interval = max_interval;
+            self
+        }
+
+        /// Requires the shape to contain `pattern` as a contiguous or cyclic subsequence.
+        pub fn required_subpattern(mut self, pattern: Vec<i16>) -> Self {
+            self.required_subpattern = Some(pattern);
+            self
+        }
+
+        /// Requires the shape to have exactly `count` unique transpositions (see `CountTranspositions`).
+        pub fn target_transpositions(mut self, count: usize) -> Self {
+            self.target_transpositions = Some(count);
+            self
+        }
+
+        /// Deduplicates results that are rotations or inversions of an already-yielded shape.
+        pub fn unique_up_to_rotation(mut self, unique: bool) -> Self {
+            self.unique_up_to_rotation = unique;
+            self
+        }
+
+        /// Streams every `ScaleShape` matching the configured constraints.
+        pub fn search(&self) -> impl Iterator<Item = ScaleShape> + '_ {
+            let mut seen = HashSet::new();
+
+            Compositions::new(self.modulus, self.cardinality, self.min_interval, self.max_interval)
+                .filter(move |intervals| self.satisfies(intervals))
+                .filter_map(move |intervals| {
+                    let shape = ScaleShape::new(intervals);
+
+                    if self.unique_up_to_rotation && !seen.insert(canonical_rotation(&shape)) {
+                        return None;
+                    }
+
+                    Some(shape)
+                })
+        }
+
+        /// Streams every `Scale` obtained by stamping a matching `ScaleShape` at `start`.
+        pub fn search_scales(&self, start: i16) -> impl Iterator<Item = Scale> + '_ {
+            self.search().map(move |shape| shape.stamp(start))
+        }
+
+        fn satisfies(&self, intervals: &[i16]) -> bool {
+            if let Some(pattern) = &self.required_subpattern {
+                if !contains_cyclic_subsequence(intervals, pattern) {
+                    return false;
+                }
+            }
+
+            if let Some(target) = self.target_transpositions {
+                let shape = ScaleShape::new(intervals.to_vec());
+                if shape.count_transpositions() != target {
+                    return false;
+                }
+            }
+
+            true
+        }
+    }
+
+    /// Lazily enumerates integer compositions of `total` into `parts` positive parts,
+    /// each within `[min, max]`.
+    struct Compositions {
+        stack: Vec<(i16, Vec<i16>)>,
+        parts: usize,
+        min: i16,
+        max: i16,
+    }
+
+    impl Compositions {
+        fn new(total: i16, parts: usize, min: i16, max: i16) -> Self {
+            Self { stack: vec![(total, Vec::new())], parts, min: min.max(1), max }
+        }
+    }
+
+    impl Iterator for Compositions {
+        type Item = Vec<i16>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            while let Some((remaining, chosen)) = self.stack.pop() {
+                if chosen.len() == self.parts {
+                    if remaining == 0 {
+                        return Some(chosen);
+                    }
+                    continue;
+                }
+
+                let parts_left = (self.parts - chosen.len()) as i16;
+                let hi = self.max.min(remaining - (parts_left - 1) * self.min);
+
+                for next_value in self.min..=hi {
+                    let mut next_chosen = chosen.clone();
+                    next_chosen.push(next_value);
+                    self.stack.push((remaining - next_value, next_chosen));
+                }
+            }
+
+            None
+        }
+    }
+
+    fn contains_cyclic_subsequence(intervals: &[i16], pattern: &[i16]) -> bool {
+        if pattern.is_empty() || pattern.len() > intervals.len() {
+            return false;
+        }
+
+        let doubled: Vec<i16> = intervals.iter().chain(intervals.iter()).cloned().collect();
+
+        doubled.windows(pattern.len()).take(intervals.len()).any(|window| window == pattern)
+    }
+
+    /// The lexicographically smallest rotation of the shape or its retrograde (inversion).
+    fn canonical_rotation(shape: &ScaleShape) -> Vec<i16> {
+        let forward = shape.intervals.clone();
+        let backward: Vec<i16> = forward.iter().rev().cloned().collect();
+
+        [forward, backward].into_iter()
+            .flat_map(|sequence| {
+                (0..sequence.len()).map(move |i| {
+                    let mut rotated = sequence.clone();
+                    rotated.rotate_left(i);
+                    rotated
+                }).collect::<Vec<_>>()
+            })
+            .min()
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod scale_shape {
+        use crate::behaviors::generate::search::scale_shape::ScaleShapeSearch;
+
+        use super::*;
+
+        #[test]
+        fn test_search() {
+            let shapes: Vec<ScaleShape> = ScaleShapeSearch::new(12, 7)
+                .min_interval(1)
+                .max_interval(2)
+                .search()
+                .collect();
+
+            assert!(shapes.iter().all(|shape| shape.intervals.iter().sum::<i16>() == 12));
+            assert!(shapes.iter().all(|shape| shape.intervals.len() == 7));
+        }
+
+        #[test]
+        fn test_required_subpattern() {
+            let shapes: Vec<ScaleShape> = ScaleShapeSearch::new(12, 5)
+                .required_subpattern(vec![2,2])
+                .search()
+                .collect();
+
+            assert!(shapes.iter().all(|shape| {
+                let doubled: Vec<i16> = shape.intervals.iter().chain(shape.intervals.iter()).cloned().collect();
+                doubled.windows(2).any(|w| w == [2,2])
+            }));
+        }
+
+        #[test]
+        fn test_unique_up_to_rotation() {
+            let shapes: Vec<ScaleShape> = ScaleShapeSearch::new(6, 3)
+                .unique_up_to_rotation(true)
+                .search()
+                .collect();
+
+            fn is_rotation_or_retrograde(a: &[i16], b: &[i16]) -> bool {
+                let b_backward: Vec<i16> = b.iter().rev().cloned().collect();
+
+                (0..b.len()).any(|i| {
+                    let mut rotated = b.to_vec();
+                    rotated.rotate_left(i);
+                    let mut rotated_backward = b_backward.clone();
+                    rotated_backward.rotate_left(i);
+                    a == rotated || a == rotated_backward
+                })
+            }
+
+            for (i, a) in shapes.iter().enumerate() {
+                for b in shapes.iter().skip(i + 1) {
+                    assert!(!is_rotation_or_retrograde(&a.intervals, &b.intervals));
+                }
+            }
+        }
+    }
+
     mod chord {
         use crate::behaviors::generate::search::chord::scale_chords_in_proximity;
 
@@ -42,4 +452,59 @@ mod tests {
             println!("{:?}", new_chords);
         }
     }
+
+    mod progression {
+        use crate::behaviors::generate::search::progression::{MusicType, ProgressionSearch, voice_leading_smoothness};
+
+        use super::*;
+
+        fn steps() -> Vec<Vec<Chord>> {
+            vec![
+                vec![Chord::new(vec![0,4,7]), Chord::new(vec![0,3,7])],
+                vec![Chord::new(vec![0,4,7]), Chord::new(vec![2,5,9])],
+            ]
+        }
+
+        #[test]
+        fn test_ranks_by_summed_score() {
+            let results = ProgressionSearch::new(steps())
+                .music_type(MusicType::new(1.0, voice_leading_smoothness))
+                .num_results(2)
+                .search();
+
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].chords, vec![Chord::new(vec![0,4,7]), Chord::new(vec![0,4,7])]);
+            assert_eq!(results[1].chords, vec![Chord::new(vec![0,3,7]), Chord::new(vec![0,4,7])]);
+        }
+
+        #[test]
+        fn test_unique_rejects_repeated_chords() {
+            let results = ProgressionSearch::new(steps())
+                .music_type(MusicType::new(1.0, voice_leading_smoothness))
+                .unique(true)
+                .num_results(10)
+                .search();
+
+            assert_eq!(results.len(), 3);
+            assert!(results.iter().all(|sequence| sequence.chords[0] != sequence.chords[1]));
+            assert_eq!(results[0].chords, vec![Chord::new(vec![0,3,7]), Chord::new(vec![0,4,7])]);
+        }
+
+        #[test]
+        fn test_beam_width_bounds_the_frontier() {
+            use crate::behaviors::generate::search::progression::penalize_forbidden_pitch_classes;
+
+            // Break the step-1 tie so the winner under beam_width(1) is unambiguous: the
+            // minor triad is penalized for containing pitch class 3.
+            let results = ProgressionSearch::new(steps())
+                .music_type(MusicType::new(1.0, voice_leading_smoothness))
+                .music_type(MusicType::new(0.1, penalize_forbidden_pitch_classes(vec![3], 12)))
+                .beam_width(1)
+                .num_results(10)
+                .search();
+
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].chords, vec![Chord::new(vec![0,4,7]), Chord::new(vec![0,4,7])]);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+use crate::types::pitch::chord::*;
+use crate::types::scale::*;
+use crate::behaviors::analyze::*;
+use itertools::Itertools;
+
+/// A fretted, tuned instrument: the open-string pitches and the usable fret span.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Instrument {
+    pub open_strings: Vec<i16>,
+    pub max_fret: i16,
+}
+
+impl Instrument {
+    pub fn new(open_strings: Vec<i16>, max_fret: i16) -> Self {
+        Self { open_strings, max_fret }
+    }
+
+    /// Enumerates playable voicings of `chord` on this instrument, ranked by fret-span
+    /// (most compact first).
+    ///
+    /// For each string, the reachable pitches are `open_pitch + fret` for `fret in 0..=max_fret`,
+    /// plus leaving the string muted. A voicing is playable when its sounded pitch classes
+    /// (reduced mod 12 via `rem_euclid`) are all chord tones and, when there are fewer strings
+    /// than distinct chord pitch classes, at most that count minus `open_strings.len()` pitch
+    /// classes are dropped.
+    pub fn voicings(&self, chord: &Chord) -> Vec<Voicing> {
+        let modulus = 12;
+
+        let mut pitch_classes: Vec<i16> = chord.pitches.iter()
+            .map(|pitch| pitch.rem_euclid(modulus))
+            .collect();
+        pitch_classes.sort();
+        pitch_classes.dedup();
+        let required = Scale::new(pitch_classes, modulus);
+
+        let max_dropped = required.pitch_classes.len().saturating_sub(self.open_strings.len());
+
+        let string_options: Vec<Vec<Option<i16>>> = self.open_strings.iter()
+            .map(|_| (0..=self.max_fret).map(Some).chain(std::iter::once(None)).collect())
+            .collect();
+
+        let mut voicings: Vec<Voicing> = string_options.into_iter()
+            .multi_cartesian_product()
+            .filter_map(|frets| {
+                let sounded: Vec<i16> = self.open_strings.iter().zip(frets.iter())
+                    .filter_map(|(&open, &fret)| fret.map(|fret| (open + fret).rem_euclid(modulus)))
+                    .unique()
+                    .collect();
+
+                if sounded.is_empty() || !required.contains_all(&sounded) {
+                    return None;
+                }
+
+                let dropped = required.pitch_classes.len() - sounded.len();
+                if dropped > max_dropped {
+                    return None;
+                }
+
+                Some(Voicing { frets })
+            })
+            .collect();
+
+        voicings.sort_by_key(|voicing| voicing.span());
+
+        voicings
+    }
+}
+
+/// A concrete fingering: the fret played per string, or `None` for a muted string.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Voicing {
+    pub frets: Vec<Option<i16>>,
+}
+
+impl Voicing {
+    /// The number of frets spanned by the fretted strings, or `0` if none are fretted.
+    pub fn span(&self) -> i16 {
+        let fretted: Vec<i16> = self.frets.iter().filter_map(|&fret| fret).collect();
+
+        match (fretted.iter().min(), fretted.iter().max()) {
+            (Some(min), Some(max)) => max - min,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voicings_full_coverage() {
+        let instrument = Instrument::new(vec![0,4,7], 2);
+        let chord = Chord::new(vec![0,4,7]);
+
+        let voicings = instrument.voicings(&chord);
+
+        assert_eq!(voicings.len(), 1);
+        assert_eq!(voicings[0].frets, vec![Some(0), Some(0), Some(0)]);
+        assert_eq!(voicings[0].span(), 0);
+    }
+
+    #[test]
+    fn test_voicings_allow_dropped_tone() {
+        let instrument = Instrument::new(vec![0,4], 0);
+        let chord = Chord::new(vec![0,4,7]);
+
+        let voicings = instrument.voicings(&chord);
+
+        assert_eq!(voicings.len(), 1);
+        assert_eq!(voicings[0].frets, vec![Some(0), Some(0)]);
+    }
+
+    #[test]
+    fn test_voicings_ranked_by_compactness() {
+        let instrument = Instrument::new(vec![0,4,7,12], 12);
+        let chord = Chord::new(vec![0,4,7]);
+
+        let voicings = instrument.voicings(&chord);
+
+        assert!(voicings.len() > 1);
+        assert_eq!(voicings[0].span(), 0);
+        assert!(voicings.windows(2).all(|pair| pair[0].span() <= pair[1].span()));
+    }
+}
@@ -2,6 +2,7 @@ use crate::types::*;
 use crate::utility::*;
 use crate::behaviors::analyze::*;
 use crate::types::{pitch::{scale::*, chord::*, melody::*}, rhythm::*};
+use crate::types::scale::{ScaleKey, ScaleMap};
 use std::ops::{Sub, Rem, Add};
 
 /// A trait representing the rotation of a cyclical collection of things.
@@ -34,16 +35,41 @@ pub trait Transpose {
     fn transpose(&self, amount: i16) -> Self;
 }
 
+/// A trait representing the inversion of pitches in a pitch struct around an axis.
+///
+/// Together with `Transpose`, this completes the standard transposition/inversion group
+/// (Tₙ/Iₙ) used in set-theoretic analysis and serial technique.
+pub trait Invert {
+    /// Inverts the pitches in a struct around `axis`.
+    fn invert(&self, axis: i16) -> Self;
+}
+
 /// A trait representing the offset of times in a rhythmic struct.
 pub trait Offset {
     /// Offsets the times in a struct by amount.
-    fn offset(&self, amount: f64) -> Self;
+    fn offset(&self, amount: Ticks) -> Self;
+}
+
+/// A trait representing transposition by scale degrees within a key, rather than by semitones.
+pub trait DiatonicTranspose {
+    /// Transposes by `degrees` scale degrees relative to `key`.
+    fn diatonic_trans(&self, key: &ScaleKey, degrees: i16) -> Self;
 }
 
 //--------------------------------------------------------------------//
 
 pub mod rotate {
     use super::*;
+    use crate::types::scale::ScaleShape;
+
+    impl Rotate for ScaleShape {
+        fn rotate(&self, n: i16) -> Self {
+            let mut intervals = self.intervals.clone();
+            intervals.rotate_left(n.rem_euclid(self.len() as i16) as usize);
+
+            Self::new(intervals)
+        }
+    }
 
     impl Rotate for ChordShape {
         fn rotate(&self, n: i16) -> Self {
@@ -191,9 +217,9 @@ pub mod rotate_mode {
     impl RotateMode for TimeScaleMap {
         fn parallel_rotate(&self, amount: i16) -> Self {
             let harmonics = self.harmonics.iter().cloned()
-                .map(|num| (num - self.eval(amount)).rem_euclid(self.modulus()))
-                .filter(|num| *num != 0.0)
-                .chain(vec![self.modulus()].into_iter()) 
+                .map(|num| (num - self.eval(Ticks(amount as i64))).rem_euclid(self.modulus()))
+                .filter(|num| *num != Ticks(0))
+                .chain(vec![self.modulus()].into_iter())
                 .collect();
 
             Self::new(harmonics, self.offset)
@@ -202,13 +228,13 @@ pub mod rotate_mode {
         fn relative_rotate(&self, amount: i16) -> Self {
             let sub = self.harmonics[amount.rem_euclid(self.len() as i16) as usize - 1];
 
-            let harmonics: Vec<f64> = self.harmonics.iter().cloned()
+            let harmonics: Vec<Ticks> = self.harmonics.iter().cloned()
                 .map(|num| (num - sub).rem_euclid(self.modulus()))
-                .filter(|num| *num != 0.0)
-                .chain(vec![self.modulus()].into_iter()) 
+                .filter(|num| *num != Ticks(0))
+                .chain(vec![self.modulus()].into_iter())
                 .collect();
 
-            Self::new(sort_vector(&harmonics), self.eval(amount))
+            Self::new(sort_vector(&harmonics), self.eval(Ticks(amount as i64)))
         }
     }
 
@@ -216,16 +242,16 @@ pub mod rotate_mode {
         fn parallel_rotate(&self, amount: i16) -> Self {
             let t = self.time_classes[amount.rem_euclid(self.len() as i16) as usize] - self.root();
 
-            let time_classes: Vec<f64> = self.time_classes
+            let time_classes: Vec<Ticks> = self.time_classes
                 .iter()
-                .map(|num| (*num - t).rem_euclid(self.modulus() as f64))
+                .map(|num| (*num - t).rem_euclid(self.modulus()))
                 .collect();
-    
+
             Self::new(time_classes, self.modulus(), self.root())
         }
-    
+
         fn relative_rotate(&self, amount: i16) -> Self {
-            Self::new(self.time_classes.clone(), self.modulus(), self.eval(amount.rem_euclid(self.len() as i16)))
+            Self::new(self.time_classes.clone(), self.modulus(), self.eval(Ticks(amount.rem_euclid(self.len() as i16) as i64)))
         }
     }
 }
@@ -487,14 +513,101 @@ pub mod transpose {
     }
 }
 
+pub mod invert {
+    use super::*;
+
+    impl Invert for Chord {
+        fn invert(&self, axis: i16) -> Self {
+            let mut pitches: Vec<i16> = self.pitches.iter().map(|p| axis - p).collect();
+            pitches.sort();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl Invert for Melody {
+        fn invert(&self, axis: i16) -> Self {
+            let pitches: Vec<i16> = self.pitches.iter().map(|p| axis - p).collect();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl Invert for PitchClassSet {
+        fn invert(&self, axis: i16) -> Self {
+            let mut pitch_classes: Vec<i16> = self.pitch_classes
+                .iter()
+                .map(|pc| (axis - pc).rem_euclid(self.modulus))
+                .collect();
+            pitch_classes.sort();
+
+            Self::new(pitch_classes, self.modulus)
+        }
+    }
+
+    impl Invert for MelodyClass {
+        fn invert(&self, axis: i16) -> Self {
+            let pitch_classes: Vec<i16> = self.pitch_classes
+                .iter()
+                .map(|pc| (axis - pc).rem_euclid(self.modulus))
+                .collect();
+
+            Self::new(pitch_classes, self.modulus)
+        }
+    }
+
+    impl Invert for PitchCycle {
+        fn invert(&self, axis: i16) -> Self {
+            let pitches: Vec<i16> = self.pitches.iter().map(|p| axis - p).collect();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl Invert for PitchClassCycle {
+        fn invert(&self, axis: i16) -> Self {
+            let pitch_classes: Vec<i16> = self.pitch_classes
+                .iter()
+                .map(|pc| (axis - pc).rem_euclid(self.modulus))
+                .collect();
+
+            Self::new(pitch_classes, self.modulus)
+        }
+    }
+}
+
+pub mod retrograde_invert {
+    use super::*;
+
+    impl Melody {
+        /// Composes retrograde (reversal) with inversion: reverses the pitch sequence, then
+        /// inverts each pitch around `axis`.
+        pub fn retrograde_invert(&self, axis: i16) -> Self {
+            let pitches: Vec<i16> = self.pitches.iter().rev().map(|p| axis - p).collect();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl MelodyShape {
+        /// Composes retrograde (reversal) with inversion: reverses the interval sequence, then
+        /// negates each interval, since a shape has no absolute axis to invert around.
+        pub fn retrograde_invert(&self) -> Self {
+            let intervals: Vec<i16> = self.intervals.iter().rev().map(|i| -i).collect();
+
+            Self::new(intervals)
+        }
+    }
+}
+
 pub mod offset {
     use super::*;
 
     impl Offset for TimeSet {
-        fn offset(&self, amount: f64) -> Self {
-            let times: Vec<f64> = self.times
+        fn offset(&self, amount: Ticks) -> Self {
+            let times: Vec<Ticks> = self.times
                 .iter()
-                .map(|n| n + amount)
+                .map(|&n| n + amount)
                 .collect();
 
             Self::new(times)
@@ -502,10 +615,10 @@ pub mod offset {
     }
 
     impl Offset for TimeClassSet {
-        fn offset(&self, amount: f64) -> Self {
-            let time_classes: Vec<f64> = self.time_classes
+        fn offset(&self, amount: Ticks) -> Self {
+            let time_classes: Vec<Ticks> = self.time_classes
                 .iter()
-                .map(|n| (n + amount).rem_euclid(self.modulus()))
+                .map(|&n| (n + amount).rem_euclid(self.modulus()))
                 .collect();
 
             Self::new(time_classes, self.modulus())
@@ -513,16 +626,16 @@ pub mod offset {
     }
 
     impl Offset for TimeScaleMap {
-        fn offset(&self, amount: f64) -> Self {
+        fn offset(&self, amount: Ticks) -> Self {
             Self::new(self.harmonics.clone(), self.offset + amount)
         }
     }
 
-    impl Offset for TimeScaleKey { 
-        fn offset(&self, amount: f64) -> Self {
-            let time_classes: Vec<f64> = self.time_classes
+    impl Offset for TimeScaleKey {
+        fn offset(&self, amount: Ticks) -> Self {
+            let time_classes: Vec<Ticks> = self.time_classes
                 .iter()
-                .map(|n| (n + amount).rem_euclid(self.modulus()))
+                .map(|&n| (n + amount).rem_euclid(self.modulus()))
                 .collect();
 
             Self::new(time_classes, self.modulus(), self.root() + amount)
@@ -530,10 +643,555 @@ pub mod offset {
     }
 }
 
+pub mod diatonic_transpose {
+    use super::*;
+    use crate::types::scale::*;
+    use crate::types::melody::Melody;
+    use crate::types::pitch::scale::PitchScaleKey;
+
+    impl PitchScaleKey {
+        /// Transposes a melody by a number of scale degrees rather than a fixed chromatic interval.
+        ///
+        /// Mirrors `Scale::diatonic_transpose`, but the degree table is keyed to this
+        /// `PitchScaleKey`'s own `pitch_classes` and `modulus` directly.
+        pub fn diatonic_transpose(&self, melody: &crate::types::pitch::melody::Melody, degrees: i16) -> crate::types::pitch::melody::Melody {
+            let len = self.pitch_classes.len() as i16;
+            let modulus = self.modulus;
+
+            let pitches = melody.pitches.iter().map(|&p| {
+                let pc = p.rem_euclid(modulus);
+                let oct = p.div_euclid(modulus);
+
+                let k = self.pitch_classes.iter()
+                    .enumerate()
+                    .min_by_key(|&(_, &member)| (pc - member).rem_euclid(modulus))
+                    .map(|(i, _)| i as i16)
+                    .unwrap();
+                let remainder = (pc - self.pitch_classes[k as usize]).rem_euclid(modulus);
+
+                let shifted = k + degrees;
+                let new_index = shifted.rem_euclid(len);
+                let carry_oct = shifted.div_euclid(len);
+
+                (oct + carry_oct) * modulus + self.pitch_classes[new_index as usize] + remainder
+            }).collect();
+
+            crate::types::pitch::melody::Melody::new(pitches)
+        }
+    }
+
+    impl Scale {
+        /// Transposes a melody by a number of scale degrees rather than a fixed chromatic interval.
+        ///
+        /// Each pitch is split into an octave and a pitch class. The pitch class is located among
+        /// (or snapped down to the nearest member of) the scale's degrees, any chromatic remainder
+        /// is set aside, the degree index is shifted by `degrees` (wrapping octaves as needed), and
+        /// the remainder is reapplied to the result. This keeps motion inside the scale instead of
+        /// applying a uniform semitone shift.
+        pub fn diatonic_transpose(&self, melody: &Melody, degrees: i16) -> Melody {
+            let len = self.len() as i16;
+            let modulus = self.modulus;
+
+            let pitches = melody.pitches.iter().map(|&p| {
+                let pc = p.rem_euclid(modulus);
+                let oct = p.div_euclid(modulus);
+
+                let k = self.pitch_classes.iter()
+                    .enumerate()
+                    .min_by_key(|&(_, &member)| (pc - member).rem_euclid(modulus))
+                    .map(|(i, _)| i as i16)
+                    .unwrap();
+                let remainder = (pc - self.pitch_classes[k as usize]).rem_euclid(modulus);
+
+                let shifted = k + degrees;
+                let new_index = shifted.rem_euclid(len);
+                let carry_oct = shifted.div_euclid(len);
+
+                (oct + carry_oct) * modulus + self.pitch_classes[new_index as usize] + remainder
+            }).collect();
+
+            Melody::new(pitches)
+        }
+    }
+}
+
+pub mod scale_degree_transpose {
+    use super::*;
+
+    impl ScaleKey {
+        /// Snaps `pitch` to its nearest scale degree at or below it, then shifts by `degrees`
+        /// scale degrees, wrapping whole octaves of `modulus()` as needed.
+        ///
+        /// Unlike `Scale::diatonic_transpose`, this snaps onto the scale exactly rather than
+        /// preserving a chromatic remainder, since a lone pitch (rather than a full melody) has
+        /// no reason to be a passing tone outside the key.
+        pub fn diatonic_transpose(&self, pitch: i16, degrees: i16) -> i16 {
+            pitch.diatonic_trans(self, degrees)
+        }
+    }
+
+    impl ScaleMap {
+        /// Snaps `pitch` to its nearest degree of the scale map at or below it, then shifts by
+        /// `degrees` scale degrees, wrapping whole octaves of `modulus()` as needed.
+        pub fn diatonic_transpose(&self, pitch: i16, degrees: i16) -> i16 {
+            let len = self.len() as i16;
+            let modulus = self.modulus();
+
+            let mut rmap: Vec<i16> = self.harmonics.clone();
+            rmap.insert(0, 0);
+            rmap.pop();
+
+            let q = (pitch - self.transposition).div_euclid(modulus);
+            let residue = (pitch - self.transposition).rem_euclid(modulus);
+
+            let i = rmap.iter()
+                .enumerate()
+                .min_by_key(|&(_, &member)| (residue - member).rem_euclid(modulus))
+                .map(|(i, _)| i as i16)
+                .unwrap();
+
+            let shifted = i + degrees;
+            let new_octave = q + shifted.div_euclid(len);
+            let new_degree = shifted.rem_euclid(len);
+
+            new_octave * modulus + rmap[new_degree as usize] + self.transposition
+        }
+    }
+}
+
+pub mod diatonic_trans {
+    use super::*;
+
+    /// Transposes a single pitch by `degrees` scale degrees relative to `key`.
+    ///
+    /// Splits the pitch into an octave and a residue, locates the residue's nearest
+    /// lower-or-equal degree in the key, shifts that degree index by `degrees` (wrapping
+    /// whole octaves of `modulus` as the index crosses the key's bounds), and recombines.
+    fn diatonic_trans_pitch(pitch: i16, key: &ScaleKey, degrees: i16) -> i16 {
+        let len = key.len() as i16;
+        let modulus = key.modulus();
+
+        let q = pitch.div_euclid(modulus);
+        let residue = pitch.rem_euclid(modulus);
+
+        let i = key.pitch_classes.iter()
+            .enumerate()
+            .min_by_key(|&(_, &member)| (residue - member).rem_euclid(modulus))
+            .map(|(i, _)| i as i16)
+            .unwrap();
+
+        let shifted = i + degrees;
+        let new_octave = q + shifted.div_euclid(len);
+        let new_degree = shifted.rem_euclid(len);
+
+        new_octave * modulus + key.pitch_classes[new_degree as usize]
+    }
+
+    impl DiatonicTranspose for i16 {
+        fn diatonic_trans(&self, key: &ScaleKey, degrees: i16) -> Self {
+            diatonic_trans_pitch(*self, key, degrees)
+        }
+    }
+
+    impl DiatonicTranspose for Chord {
+        fn diatonic_trans(&self, key: &ScaleKey, degrees: i16) -> Self {
+            let pitches = self.pitches.iter().map(|&p| diatonic_trans_pitch(p, key, degrees)).collect();
+            Self::new(pitches)
+        }
+    }
+
+    impl DiatonicTranspose for Melody {
+        fn diatonic_trans(&self, key: &ScaleKey, degrees: i16) -> Self {
+            let pitches = self.pitches.iter().map(|&p| diatonic_trans_pitch(p, key, degrees)).collect();
+            Self::new(pitches)
+        }
+    }
+}
+
+pub mod diatonic_transpose_key {
+    use super::*;
+
+    /// A trait representing transposition by scale degrees within a `PitchScaleKey`, rather
+    /// than by semitones.
+    ///
+    /// Sibling to the top-level `DiatonicTranspose` trait (implemented in `diatonic_trans`),
+    /// which is keyed to the older `ScaleKey`.
+    pub trait DiatonicTranspose {
+        /// Transposes by `degrees` scale degrees relative to `key`.
+        fn diatonic_transpose(&self, key: &PitchScaleKey, degrees: i16) -> Self;
+    }
+
+    /// Snaps each pitch to its nearest scale degree at or below it, shifts that degree index
+    /// by `degrees` (wrapping whole octaves of `modulus` as needed), and reapplies any
+    /// chromatic remainder so out-of-scale passing tones keep their offset from the scale.
+    fn diatonic_transpose_pitches(pitches: &[i16], key: &PitchScaleKey, degrees: i16) -> Vec<i16> {
+        if degrees == 0 {
+            return pitches.to_vec();
+        }
+
+        let len = key.pitch_classes.len() as i16;
+        let modulus = key.modulus;
+
+        pitches.iter().map(|&p| {
+            let pc = p.rem_euclid(modulus);
+            let oct = p.div_euclid(modulus);
+
+            let i = key.pitch_classes.iter()
+                .enumerate()
+                .min_by_key(|&(_, &member)| (pc - member).rem_euclid(modulus))
+                .map(|(i, _)| i as i16)
+                .unwrap();
+            let remainder = (pc - key.pitch_classes[i as usize]).rem_euclid(modulus);
+
+            let shifted = i + degrees;
+            let in_scale_index = shifted.rem_euclid(len);
+            let carry = shifted.div_euclid(len);
+
+            key.pitch_classes[in_scale_index as usize] + modulus * (oct + carry) + remainder
+        }).collect()
+    }
+
+    impl DiatonicTranspose for Melody {
+        fn diatonic_transpose(&self, key: &PitchScaleKey, degrees: i16) -> Self {
+            Self::new(diatonic_transpose_pitches(&self.pitches, key, degrees))
+        }
+    }
+
+    impl DiatonicTranspose for Chord {
+        fn diatonic_transpose(&self, key: &PitchScaleKey, degrees: i16) -> Self {
+            let mut pitches = diatonic_transpose_pitches(&self.pitches, key, degrees);
+            pitches.sort();
+
+            Self::new(pitches)
+        }
+    }
+}
+
+pub mod diatonic_transpose_scale {
+    use super::*;
+    use crate::types::scale::*;
+    use crate::types::melody::{Melody, MelodyClass, PitchCycle, PitchClassCycle};
+
+    /// A trait representing transposition by scale degrees within a `Scale`, rather than by
+    /// semitones, for the older `Melody`/`MelodyClass`/cycle types.
+    ///
+    /// Sibling to `diatonic_transpose_key::DiatonicTranspose`, which is keyed to the newer
+    /// `PitchScaleKey`.
+    pub trait DiatonicTranspose {
+        /// Transposes by `degrees` scale degrees relative to `scale`.
+        fn diatonic_transpose(&self, scale: &Scale, degrees: i16) -> Self;
+    }
+
+    /// Snaps a pitch class to its nearest scale degree at or below it, and returns the index of
+    /// that degree shifted by `degrees` (wrapping around `scale`'s own degree count).
+    fn shifted_degree_index(pc: i16, scale: &Scale, degrees: i16) -> i16 {
+        let modulus = scale.modulus();
+        let len = scale.len() as i16;
+
+        let i = scale.pitch_classes.iter()
+            .enumerate()
+            .min_by_key(|&(_, &member)| (pc.rem_euclid(modulus) - member).rem_euclid(modulus))
+            .map(|(i, _)| i as i16)
+            .unwrap();
+
+        (i + degrees).rem_euclid(len)
+    }
+
+    impl DiatonicTranspose for Melody {
+        /// Unlike the modular types, the octave carried by shifting past the scale's own degree
+        /// count is reapplied as a whole multiple of `scale.modulus()`.
+        fn diatonic_transpose(&self, scale: &Scale, degrees: i16) -> Self {
+            let modulus = scale.modulus();
+            let len = scale.len() as i16;
+
+            let pitches = self.pitches.iter().map(|&p| {
+                let oct = p.div_euclid(modulus);
+                let pc = p.rem_euclid(modulus);
+
+                let i = scale.pitch_classes.iter()
+                    .enumerate()
+                    .min_by_key(|&(_, &member)| (pc - member).rem_euclid(modulus))
+                    .map(|(i, _)| i as i16)
+                    .unwrap();
+
+                let shifted = i + degrees;
+                let carry = shifted.div_euclid(len);
+                let new_index = shifted.rem_euclid(len);
+
+                (oct + carry) * modulus + scale.pitch_classes[new_index as usize]
+            }).collect();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl DiatonicTranspose for MelodyClass {
+        fn diatonic_transpose(&self, scale: &Scale, degrees: i16) -> Self {
+            let pitch_classes = self.pitch_classes.iter().map(|&pc| {
+                let new_index = shifted_degree_index(pc, scale, degrees);
+                scale.pitch_classes[new_index as usize]
+            }).collect();
+
+            Self::new(pitch_classes, self.modulus)
+        }
+    }
+
+    impl DiatonicTranspose for PitchCycle {
+        fn diatonic_transpose(&self, scale: &Scale, degrees: i16) -> Self {
+            let pitches = self.pitches.iter().map(|&p| {
+                let new_index = shifted_degree_index(p, scale, degrees);
+                scale.pitch_classes[new_index as usize]
+            }).collect();
+
+            Self::new(pitches)
+        }
+    }
+
+    impl DiatonicTranspose for PitchClassCycle {
+        fn diatonic_transpose(&self, scale: &Scale, degrees: i16) -> Self {
+            let pitch_classes = self.pitch_classes.iter().map(|&pc| {
+                let new_index = shifted_degree_index(pc, scale, degrees);
+                scale.pitch_classes[new_index as usize]
+            }).collect();
+
+            Self::new(pitch_classes, self.modulus)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod diatonic_trans {
+        use super::*;
+
+        #[test]
+        fn test_pitch() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+
+            assert_eq!(4.diatonic_trans(&key, 1), 5);
+        }
+
+        #[test]
+        fn test_chord() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let chord = Chord::new(vec![0,4,7]);
+            let result = Chord::new(vec![2,5,9]);
+
+            assert_eq!(chord.diatonic_trans(&key, 1), result);
+        }
+
+        #[test]
+        fn test_melody_octave_wrap() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![11]);
+            let result = Melody::new(vec![12]);
+
+            assert_eq!(melody.diatonic_trans(&key, 1), result);
+        }
+    }
+
+    mod diatonic_transpose {
+        use crate::types::scale::*;
+        use crate::types::melody::Melody;
+
+        #[test]
+        fn test_scale() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![0,4,7]);
+            let result = Melody::new(vec![2,5,9]);
+
+            assert_eq!(scale.diatonic_transpose(&melody, 1), result);
+        }
+
+        #[test]
+        fn test_chromatic_remainder() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![1]);
+            let result = Melody::new(vec![3]);
+
+            assert_eq!(scale.diatonic_transpose(&melody, 1), result);
+        }
+
+        #[test]
+        fn test_pitch_scale_key() {
+            use crate::types::pitch::scale::PitchScaleKey;
+            use crate::types::pitch::melody::Melody;
+
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![4,7]);
+            let result = Melody::new(vec![5,9]);
+
+            assert_eq!(key.diatonic_transpose(&melody, 1), result);
+        }
+    }
+
+    mod diatonic_transpose_key {
+        use super::*;
+        use super::super::diatonic_transpose_key::DiatonicTranspose;
+
+        #[test]
+        fn test_melody() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![0,4]);
+            let result = Melody::new(vec![4,7]);
+
+            assert_eq!(melody.diatonic_transpose(&key, 2), result);
+        }
+
+        #[test]
+        fn test_chord_is_sorted() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let chord = Chord::new(vec![0,4,7]);
+            let result = Chord::new(vec![2,5,9]);
+
+            assert_eq!(chord.diatonic_transpose(&key, 1), result);
+        }
+
+        #[test]
+        fn test_octave_wrap() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![11]);
+            let result = Melody::new(vec![12]);
+
+            assert_eq!(melody.diatonic_transpose(&key, 1), result);
+        }
+
+        #[test]
+        fn test_zero_degrees_is_identity() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![1,4,11]);
+
+            assert_eq!(melody.diatonic_transpose(&key, 0), melody);
+        }
+
+        #[test]
+        fn test_invertible_for_in_scale_notes() {
+            let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+            let melody = Melody::new(vec![4]);
+
+            let transposed = melody.diatonic_transpose(&key, 2);
+
+            assert_eq!(transposed.diatonic_transpose(&key, -2), melody);
+        }
+    }
+
+    mod diatonic_transpose_scale {
+        use crate::types::scale::*;
+        use crate::types::melody::{Melody, MelodyClass, PitchCycle, PitchClassCycle};
+        use super::super::diatonic_transpose_scale::DiatonicTranspose;
+
+        #[test]
+        fn test_melody_mixed_thirds() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![0,4,7]);
+            let result = Melody::new(vec![4,7,11]);
+
+            assert_eq!(melody.diatonic_transpose(&scale, 2), result);
+        }
+
+        #[test]
+        fn test_melody_octave_wrap() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![11]);
+            let result = Melody::new(vec![12]);
+
+            assert_eq!(melody.diatonic_transpose(&scale, 1), result);
+        }
+
+        #[test]
+        fn test_melody_snaps_non_scale_pitch_down() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![1]);
+            let result = Melody::new(vec![2]);
+
+            assert_eq!(melody.diatonic_transpose(&scale, 1), result);
+        }
+
+        #[test]
+        fn test_melody_negative_degrees() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody = Melody::new(vec![4]);
+            let result = Melody::new(vec![0]);
+
+            assert_eq!(melody.diatonic_transpose(&scale, -2), result);
+        }
+
+        #[test]
+        fn test_melody_class() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let melody_class = MelodyClass::new(vec![0,4], 12);
+            let result = MelodyClass::new(vec![4,7], 12);
+
+            assert_eq!(melody_class.diatonic_transpose(&scale, 2), result);
+        }
+
+        #[test]
+        fn test_pitch_cycle() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let pitch_cycle = PitchCycle::new(vec![0,4]);
+            let result = PitchCycle::new(vec![4,7]);
+
+            assert_eq!(pitch_cycle.diatonic_transpose(&scale, 2), result);
+        }
+
+        #[test]
+        fn test_pitch_class_cycle() {
+            let scale = Scale::new(vec![0,2,4,5,7,9,11], 12);
+            let pitch_class_cycle = PitchClassCycle::new(vec![0,4], 12);
+            let result = PitchClassCycle::new(vec![4,7], 12);
+
+            assert_eq!(pitch_class_cycle.diatonic_transpose(&scale, 2), result);
+        }
+    }
+
+    mod scale_degree_transpose {
+        use super::*;
+
+        #[test]
+        fn test_scale_key() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+
+            assert_eq!(key.diatonic_transpose(4, 1), 5);
+        }
+
+        #[test]
+        fn test_scale_key_snaps_chromatic_pitch() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+
+            assert_eq!(key.diatonic_transpose(1, 1), 2);
+        }
+
+        #[test]
+        fn test_scale_key_octave_wrap() {
+            let key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+
+            assert_eq!(key.diatonic_transpose(11, 1), 12);
+        }
+
+        #[test]
+        fn test_scale_map() {
+            let scale_map = ScaleMap::new(vec![2,4,5,7,9,11,12], 0);
+
+            assert_eq!(scale_map.diatonic_transpose(4, 1), 5);
+        }
+
+        #[test]
+        fn test_scale_map_octave_wrap() {
+            let scale_map = ScaleMap::new(vec![2,4,5,7,9,11,12], 0);
+
+            assert_eq!(scale_map.diatonic_transpose(11, 1), 12);
+        }
+
+        #[test]
+        fn test_scale_map_no_shift() {
+            let scale_map = ScaleMap::new(vec![2,4,5,7,9,11,12], 0);
+
+            assert_eq!(scale_map.diatonic_transpose(0, 0), 0);
+        }
+    }
+
     mod rotate {
         use super::*;
 
@@ -555,6 +1213,17 @@ mod tests {
             assert_eq!(rotation, result);
         }
 
+        #[test]
+        fn test_scale_shape() {
+            use crate::types::scale::ScaleShape;
+
+            let scale_shape = ScaleShape::new(vec![4,7,2,4]);
+            let rotation = scale_shape.rotate(2);
+            let result = ScaleShape::new(vec![2,4,4,7]);
+
+            assert_eq!(rotation, result);
+        }
+
         #[test]
         fn test_pitch_cycle() {
             let pitch_cycle = PitchCycle::new(vec![2,7,3,-3]);
@@ -721,4 +1390,133 @@ mod tests {
             assert_eq!(melodic_map.transpose(-4), transposition);
         }
     }
+
+    mod invert {
+        use super::*;
+
+        #[test]
+        fn test_chord() {
+            let chord = Chord::new(vec![0,3,7]);
+            let inversion = Chord::new(vec![5,9,12]);
+
+            assert_eq!(chord.invert(12), inversion);
+        }
+
+        #[test]
+        fn test_chord_is_own_inverse() {
+            let chord = Chord::new(vec![0,3,7]);
+
+            assert_eq!(chord.invert(12).invert(12), chord);
+        }
+
+        #[test]
+        fn test_melody() {
+            let melody = Melody::new(vec![0,4,7]);
+            let inversion = Melody::new(vec![10,6,3]);
+
+            assert_eq!(melody.invert(10), inversion);
+        }
+
+        #[test]
+        fn test_pitch_class_set() {
+            let pitch_class_set = PitchClassSet::new(vec![0,3,7], 12);
+            let inversion = PitchClassSet::new(vec![2,5,10], 12);
+
+            assert_eq!(pitch_class_set.invert(5), inversion);
+        }
+
+        #[test]
+        fn test_pitch_class_set_is_own_inverse() {
+            let pitch_class_set = PitchClassSet::new(vec![0,3,7], 12);
+
+            assert_eq!(pitch_class_set.invert(5).invert(5), pitch_class_set);
+        }
+
+        #[test]
+        fn test_melody_class() {
+            let melody_class = MelodyClass::new(vec![0,3,7], 12);
+            let inversion = MelodyClass::new(vec![5,2,10], 12);
+
+            assert_eq!(melody_class.invert(5), inversion);
+        }
+
+        #[test]
+        fn test_pitch_cycle() {
+            let pitch_cycle = PitchCycle::new(vec![0,4,7]);
+            let inversion = PitchCycle::new(vec![10,6,3]);
+
+            assert_eq!(pitch_cycle.invert(10), inversion);
+        }
+
+        #[test]
+        fn test_pitch_class_cycle() {
+            let pitch_class_cycle = PitchClassCycle::new(vec![0,3,7], 12);
+            let inversion = PitchClassCycle::new(vec![5,2,10], 12);
+
+            assert_eq!(pitch_class_cycle.invert(5), inversion);
+        }
+
+        #[test]
+        fn test_transpose_then_invert_equals_invert_by_shifted_axis() {
+            let melody = Melody::new(vec![0,4,7]);
+
+            assert_eq!(melody.transpose(3).invert(10), melody.invert(7));
+        }
+    }
+
+    mod retrograde_invert {
+        use super::*;
+
+        #[test]
+        fn test_melody() {
+            let melody = Melody::new(vec![0,4,7]);
+            let result = Melody::new(vec![3,6,10]);
+
+            assert_eq!(melody.retrograde_invert(10), result);
+        }
+
+        #[test]
+        fn test_melody_shape() {
+            let shape = MelodyShape::new(vec![2,3]);
+            let result = MelodyShape::new(vec![-3,-2]);
+
+            assert_eq!(shape.retrograde_invert(), result);
+        }
+    }
+
+    mod offset {
+        use super::*;
+
+        #[test]
+        fn test_time_set() {
+            let time_set = TimeSet::new(vec![Ticks(0), Ticks(48), Ticks(96)]);
+            let result = TimeSet::new(vec![Ticks(24), Ticks(72), Ticks(120)]);
+
+            assert_eq!(time_set.offset(Ticks(24)), result);
+        }
+
+        #[test]
+        fn test_time_class_set() {
+            let time_class_set = TimeClassSet::new(vec![Ticks(0), Ticks(24), Ticks(48)], Ticks(96));
+            let result = TimeClassSet::new(vec![Ticks(12), Ticks(36), Ticks(60)], Ticks(96));
+
+            assert_eq!(time_class_set.offset(Ticks(12)), result);
+        }
+
+        #[test]
+        fn test_time_scale_map() {
+            let time_scale_map = TimeScaleMap::new(vec![Ticks(24), Ticks(48), Ticks(72)], Ticks(12));
+            let result = TimeScaleMap::new(vec![Ticks(24), Ticks(48), Ticks(72)], Ticks(18));
+
+            assert_eq!(time_scale_map.offset(Ticks(6)), result);
+        }
+
+        #[test]
+        fn test_time_scale_key() {
+            let time_scale_key = TimeScaleKey::new(vec![Ticks(0), Ticks(24), Ticks(48)], Ticks(72), Ticks(0));
+            let result = TimeScaleKey::new(vec![Ticks(12), Ticks(36), Ticks(60)], Ticks(72), Ticks(12));
+
+            assert_eq!(time_scale_key.offset(Ticks(12)), result);
+        }
+    }
 }
\ No newline at end of file
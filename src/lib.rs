@@ -19,8 +19,3 @@ pub mod behaviors;
 /// The `utility` module provides general purpose tools used throughout the library.
 pub mod utility;
 
-/// API Module
-/// 
-/// The `api` module exposes items in the library to the public API.
-mod api;
-pub use api::*;
@@ -1,10 +1,3 @@
-use std::fmt;
-
-/// Chord Module
-/// 
-/// The `chord` module contains types related to chords.
-pub mod chord;
-
 /// Melody Module
 /// 
 /// The `melody` moudle contains types related to melodies.
@@ -21,50 +14,20 @@ pub mod scale;
 pub mod progression;
 
 /// Rhythm Module
-/// 
+///
 /// The `rhythm` module contains types related to time.
 pub mod rhythm;
 
-// Display support for debugging
-
-// impl<T: fmt::Display> fmt::Display for ResidueSet<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let elements: Vec<String> = self.residue_classes.iter().map(|e| e.to_string()).collect();
-//         write!(f, "{} mod {}", elements.join(", "), self.modulus)
-//     }
-// }
-
-// impl<T: fmt::Display> fmt::Display for ScaleMap<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let elements: Vec<String> = self.harmonics.iter().map(|e| e.to_string()).collect();
-//         write!(f, "{:?} + {}", elements, self.offset)
-//     }
-// }
-
-// impl<T: fmt::Display> fmt::Display for IndexedResidues<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let elements: Vec<String> = self.residue_classes.iter().map(|e| e.to_string()).collect();
-//         write!(f, "{} mod {}", elements.join(", "), self.modulus)
-//     }
-// }
-
-// impl<T: fmt::Display> fmt::Display for ScaleShape<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let elements: Vec<String> = self.intervals.iter().map(|e| e.to_string()).collect();
-//         write!(f, "{:?}", elements)
-//     }
-// }
-
-// impl<T: fmt::Display> fmt::Display for Set<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let numbers_str: Vec<String> = self.numbers.iter().map(|n| n.to_string()).collect();
-//         write!(f, "[{}]", numbers_str.join(", "))
-//     }
-// }
-
-// impl<T: fmt::Display> fmt::Display for Shape<T> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         let intervals_str: Vec<String> = self.intervals.iter().map(|n| n.to_string()).collect();
-//         write!(f, "[{}]", intervals_str.join(", "))
-//     }
-// }
+/// Pitch Module
+///
+/// The `pitch` module contains a parallel family of pitch and pitch-class types
+/// (sets, scales, melodies, chords) alongside key-aware note-name spelling. This
+/// declaration is what first makes `types::pitch::*` reachable from the crate
+/// root; several `behaviors` modules that reference pitch-family types by bare
+/// name (rather than a fully-qualified path) depend on it being present.
+pub mod pitch;
+
+/// Spelling Module
+///
+/// The `spelling` module provides note-name spelling and `Display` support for pitch types.
+pub mod spelling;
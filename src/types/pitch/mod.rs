@@ -9,6 +9,11 @@ pub mod chord;
 pub mod melody;
 
 /// Scale Module
-/// 
+///
 /// The `scale` module contains types related to scales.
-pub mod scale;
\ No newline at end of file
+pub mod scale;
+
+/// Spelling Module
+///
+/// The `spelling` module provides key-aware note-name spelling and parsing for pitch types.
+pub mod spelling;
\ No newline at end of file
@@ -0,0 +1,322 @@
+use crate::types::pitch::scale::{PitchClassSet, PitchScaleKey};
+use crate::types::pitch::melody::Melody;
+use crate::types::spelling::{Letter, NoteName};
+use std::fmt;
+use std::str::FromStr;
+
+/// The musical alphabet in letter order, used to step from one letter to the next.
+const LETTER_CYCLE: [Letter; 7] = [
+    Letter::C, Letter::D, Letter::E, Letter::F, Letter::G, Letter::A, Letter::B
+];
+
+/// Root pitch classes (mod 12) of the keys that are conventionally spelled with flats.
+const FLAT_KEY_ROOTS: [i16; 6] = [1, 3, 5, 6, 8, 10];
+
+/// Reports whether a key rooted at `root` (mod 12) is conventionally spelled with flats.
+fn prefers_flats(root: i16) -> bool {
+    FLAT_KEY_ROOTS.contains(&root.rem_euclid(12))
+}
+
+/// The natural (no-accidental) pitch class of a letter, e.g. `D` is 2.
+fn natural_value(letter: Letter) -> i16 {
+    match letter {
+        Letter::C => 0, Letter::D => 2, Letter::E => 4, Letter::F => 5,
+        Letter::G => 7, Letter::A => 9, Letter::B => 11
+    }
+}
+
+/// The signed distance from `natural` to `pitch_class` (mod 12), in the range -6..=5.
+///
+/// This is the accidental needed to raise or lower a letter's natural pitch class to
+/// `pitch_class`, picked as the shortest such distance so spellings favor single
+/// sharps/flats over double ones.
+fn signed_offset(pitch_class: i16, natural: i16) -> i16 {
+    (pitch_class - natural + 6).rem_euclid(12) - 6
+}
+
+/// Error returned when note-name spelling or parsing can't be carried out.
+#[derive(PartialEq, Debug, Clone)]
+pub enum SpellingError {
+    /// Spelling was attempted against a key or collection whose modulus isn't 12.
+    UnsupportedModulus(i16),
+    /// A string failed to parse as a `SpelledNote`.
+    InvalidNoteName(String)
+}
+
+impl fmt::Display for SpellingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpellingError::UnsupportedModulus(modulus) =>
+                write!(f, "note-name spelling is only defined for modulus 12, got {}", modulus),
+            SpellingError::InvalidNoteName(note) =>
+                write!(f, "'{}' is not a valid spelled note name", note)
+        }
+    }
+}
+
+/// A human-readable note name, letter plus accidental plus octave: e.g. `C#4`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct SpelledNote {
+    pub letter: Letter,
+    pub accidental: i16,
+    pub octave: i16
+}
+
+impl SpelledNote {
+    /// The absolute pitch (semitones from C0) this spelling names.
+    pub fn pitch(&self) -> i16 {
+        natural_value(self.letter) + self.accidental + self.octave * 12
+    }
+
+    /// The pitch class (mod 12) this spelling names, independent of octave.
+    pub fn pitch_class(&self) -> i16 {
+        self.pitch().rem_euclid(12)
+    }
+}
+
+/// Renders an accidental offset as a run of `#` (sharps) or `b` (flats).
+fn accidental_string(accidental: i16) -> String {
+    if accidental > 0 {
+        "#".repeat(accidental as usize)
+    } else {
+        "b".repeat((-accidental) as usize)
+    }
+}
+
+impl fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.letter, accidental_string(self.accidental), self.octave)
+    }
+}
+
+impl FromStr for SpelledNote {
+    type Err = SpellingError;
+
+    /// Parses a note name like `"C#4"` or `"Bbb-1"` back into a `SpelledNote`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars().peekable();
+
+        let letter = match chars.next() {
+            Some('C') => Letter::C, Some('D') => Letter::D, Some('E') => Letter::E,
+            Some('F') => Letter::F, Some('G') => Letter::G, Some('A') => Letter::A,
+            Some('B') => Letter::B,
+            _ => return Err(SpellingError::InvalidNoteName(s.to_string()))
+        };
+
+        let mut accidental = 0;
+        while let Some(&c) = chars.peek() {
+            match c {
+                '#' => { accidental += 1; chars.next(); }
+                'b' => { accidental -= 1; chars.next(); }
+                _ => break
+            }
+        }
+
+        let octave: i16 = chars.collect::<String>().parse()
+            .map_err(|_| SpellingError::InvalidNoteName(s.to_string()))?;
+
+        Ok(SpelledNote { letter, accidental, octave })
+    }
+}
+
+/// Assigns a letter to each of `key`'s scale degrees, cycling C-D-E-F-G-A-B starting from
+/// the root's conventional letter, so each letter is used once across a diatonic key.
+fn key_letters(key: &PitchScaleKey) -> Vec<Letter> {
+    let root_letter = NoteName::from_pitch_class(key.pitch_classes[0], prefers_flats(key.pitch_classes[0])).letter;
+    let start = LETTER_CYCLE.iter().position(|&letter| letter == root_letter).unwrap();
+
+    (0..key.pitch_classes.len()).map(|i| LETTER_CYCLE[(start + i) % 7]).collect()
+}
+
+/// Spells a single pitch class in the context of `key`: finds the nearest scale degree at
+/// or below it, reuses that degree's letter, and expresses the difference as an accidental.
+fn spell_pitch_class(pitch_class: i16, key: &PitchScaleKey, letters: &[Letter]) -> (Letter, i16) {
+    let degree = key.pitch_classes.iter()
+        .enumerate()
+        .min_by_key(|&(_, &member)| (pitch_class - member).rem_euclid(key.modulus))
+        .map(|(i, _)| i)
+        .unwrap();
+
+    let letter = letters[degree % letters.len()];
+    let accidental = signed_offset(pitch_class.rem_euclid(key.modulus), natural_value(letter));
+
+    (letter, accidental)
+}
+
+/// Spells a list of pitch classes (no octave) against `key`, gated on both being modulus 12.
+fn spell_pitch_classes(pitch_classes: &[i16], modulus: i16, key: &PitchScaleKey) -> Result<Vec<SpelledNote>, SpellingError> {
+    if modulus != 12 {
+        return Err(SpellingError::UnsupportedModulus(modulus));
+    }
+    if key.modulus != 12 {
+        return Err(SpellingError::UnsupportedModulus(key.modulus));
+    }
+
+    let letters = key_letters(key);
+
+    Ok(pitch_classes.iter().map(|&pitch_class| {
+        let (letter, accidental) = spell_pitch_class(pitch_class, key, &letters);
+        SpelledNote { letter, accidental, octave: 0 }
+    }).collect())
+}
+
+impl PitchClassSet {
+    /// Spells this set's pitch classes in the context of `key`.
+    pub fn to_spelled(&self, key: &PitchScaleKey) -> Result<Vec<SpelledNote>, SpellingError> {
+        spell_pitch_classes(&self.pitch_classes, self.modulus, key)
+    }
+
+    /// Spells this set's pitch classes as letter-plus-accidental strings, in the context
+    /// of `key`, e.g. `["C", "D", "E", "F", "G", "A", "B"]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this set or `key` isn't modulus 12; use `to_spelled` to handle that
+    /// case explicitly.
+    pub fn spell(&self, key: &PitchScaleKey) -> Vec<String> {
+        self.to_spelled(key).unwrap().iter()
+            .map(|note| format!("{}{}", note.letter, accidental_string(note.accidental)))
+            .collect()
+    }
+}
+
+impl PitchScaleKey {
+    /// Spells this key's own pitch classes, using `key` for letter and accidental choices.
+    pub fn to_spelled(&self, key: &PitchScaleKey) -> Result<Vec<SpelledNote>, SpellingError> {
+        spell_pitch_classes(&self.pitch_classes, self.modulus, key)
+    }
+}
+
+impl Melody {
+    /// Spells this melody's absolute pitches in the context of `key`, one octave-bearing
+    /// `SpelledNote` per pitch.
+    pub fn to_spelled(&self, key: &PitchScaleKey) -> Result<Vec<SpelledNote>, SpellingError> {
+        if key.modulus != 12 {
+            return Err(SpellingError::UnsupportedModulus(key.modulus));
+        }
+
+        let letters = key_letters(key);
+
+        Ok(self.pitches.iter().map(|&pitch| {
+            let (letter, accidental) = spell_pitch_class(pitch.rem_euclid(12), key, &letters);
+
+            SpelledNote { letter, accidental, octave: pitch.div_euclid(12) }
+        }).collect())
+    }
+
+    /// Spells this melody's absolute pitches as letter-plus-accidental-plus-octave strings,
+    /// in the context of `key`, e.g. `["G#4", "A4"]` in A major but `["Ab4", "A4"]` in Eb major.
+    ///
+    /// The octave number is derived from `pitch.div_euclid(12)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` isn't modulus 12; use `to_spelled` to handle that case explicitly.
+    pub fn spell(&self, key: &PitchScaleKey) -> Vec<String> {
+        self.to_spelled(key).unwrap().iter().map(|note| note.to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spelled_note_display() {
+        let note = SpelledNote { letter: Letter::F, accidental: 1, octave: 4 };
+
+        assert_eq!(note.to_string(), "F#4");
+    }
+
+    #[test]
+    fn test_spelled_note_roundtrip() {
+        let note: SpelledNote = "Bb3".parse().unwrap();
+
+        assert_eq!(note, SpelledNote { letter: Letter::B, accidental: -1, octave: 3 });
+        assert_eq!(note.to_string(), "Bb3");
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_letter() {
+        assert!("H4".parse::<SpelledNote>().is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_octave() {
+        assert!("C#".parse::<SpelledNote>().is_err());
+    }
+
+    #[test]
+    fn test_pitch_class_key_spells_with_sharps() {
+        let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let set = PitchClassSet::new(vec![1], 12);
+
+        let spelled = set.to_spelled(&key).unwrap();
+
+        assert_eq!(spelled[0].letter, Letter::C);
+        assert_eq!(spelled[0].accidental, 1);
+    }
+
+    #[test]
+    fn test_flat_key_spells_degrees_with_each_letter_once() {
+        let key = PitchScaleKey::new(vec![1,3,5,6,8,10,0], 12, 1);
+
+        let spelled = key.to_spelled(&key).unwrap();
+        let letters: Vec<Letter> = spelled.iter().map(|note| note.letter).collect();
+
+        assert_eq!(letters, vec![Letter::D, Letter::E, Letter::F, Letter::G, Letter::A, Letter::B, Letter::C]);
+        assert_eq!(spelled[0].accidental, -1);
+    }
+
+    #[test]
+    fn test_melody_spelling_carries_octave() {
+        let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let melody = Melody::new(vec![-1, 15]);
+
+        let spelled = melody.to_spelled(&key).unwrap();
+
+        assert_eq!(spelled[0], SpelledNote { letter: Letter::B, accidental: 0, octave: -1 });
+        assert_eq!(spelled[1], SpelledNote { letter: Letter::D, accidental: 1, octave: 1 });
+    }
+
+    #[test]
+    fn test_to_spelled_rejects_non_twelve_modulus() {
+        let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let set = PitchClassSet::new(vec![0,2,4], 7);
+
+        assert_eq!(set.to_spelled(&key), Err(SpellingError::UnsupportedModulus(7)));
+    }
+
+    #[test]
+    fn test_pitch_class_set_spell() {
+        let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let set = PitchClassSet::new(vec![0,2,4,5,7,9,11], 12);
+
+        assert_eq!(set.spell(&key), vec!["C", "D", "E", "F", "G", "A", "B"]);
+    }
+
+    #[test]
+    fn test_melody_spell_sharp_in_a_major() {
+        let a_major = PitchScaleKey::new(vec![1,2,4,6,8,9,11], 12, 9);
+        let melody = Melody::new(vec![8]);
+
+        assert_eq!(melody.spell(&a_major), vec!["G#0"]);
+    }
+
+    #[test]
+    fn test_melody_spell_flat_in_eb_major() {
+        let eb_major = PitchScaleKey::new(vec![3,5,7,8,10,0,2], 12, 3);
+        let melody = Melody::new(vec![8]);
+
+        assert_eq!(melody.spell(&eb_major), vec!["Ab0"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pitch_class_set_spell_rejects_non_twelve_modulus() {
+        let key = PitchScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+        let set = PitchClassSet::new(vec![0,2,4], 7);
+
+        set.spell(&key);
+    }
+}
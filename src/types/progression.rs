@@ -1,4 +1,4 @@
-use crate::types::{chord::*, scale::*};
+use crate::types::{pitch::chord::*, scale::*};
 use crate::behaviors::{analyze::*, transform::*};
 
 use num::integer;
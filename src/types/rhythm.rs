@@ -1,33 +1,145 @@
 use crate::types::*;
 use crate::utility::*;
 
-/* Rhythm types and behaviors are on hold
-   until I find a suitable replacement
-   for using floats, because floats suck. */
+/* Rhythm types are built on `Ticks`, an exact integer duration unit, rather than f64 seconds.
+   This keeps ordering, uniqueness, and modulus arithmetic exact, so the Time* types can derive
+   equality like their pitch counterparts instead of relying on epsilon comparisons. */
+
+/// An exact duration expressed in ticks, the rhythmic analog of a pitch's `i16`.
+///
+/// Ticks are counted at a fixed resolution (`Ticks::RESOLUTION` ticks per whole note), chosen
+/// so that common note values, dotted notes, and tuplets land on exact integers.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct Ticks(pub i64);
+
+impl Ticks {
+    /// Ticks per whole note.
+    pub const RESOLUTION: i64 = 192;
+
+    pub fn rem_euclid(self, modulus: Ticks) -> Ticks {
+        Ticks(self.0.rem_euclid(modulus.0))
+    }
+
+    pub fn div_euclid(self, modulus: Ticks) -> Ticks {
+        Ticks(self.0.div_euclid(modulus.0))
+    }
+
+    /// Converts to seconds at a given tempo, in beats (quarter notes) per minute.
+    pub fn to_seconds(self, bpm: f64) -> f64 {
+        let ticks_per_quarter = Self::RESOLUTION as f64 / 4.0;
+
+        (self.0 as f64 / ticks_per_quarter) * (60.0 / bpm)
+    }
+
+    /// Converts from seconds at a given tempo, in beats (quarter notes) per minute, rounding
+    /// to the nearest tick.
+    pub fn from_seconds(seconds: f64, bpm: f64) -> Self {
+        let ticks_per_quarter = Self::RESOLUTION as f64 / 4.0;
+        let quarters = seconds / (60.0 / bpm);
+
+        Ticks((quarters * ticks_per_quarter).round() as i64)
+    }
+}
+
+impl std::ops::Add for Ticks {
+    type Output = Ticks;
+
+    fn add(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for Ticks {
+    type Output = Ticks;
+
+    fn sub(self, rhs: Ticks) -> Ticks {
+        Ticks(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul<i64> for Ticks {
+    type Output = Ticks;
+
+    fn mul(self, rhs: i64) -> Ticks {
+        Ticks(self.0 * rhs)
+    }
+}
+
+impl std::ops::Neg for Ticks {
+    type Output = Ticks;
+
+    fn neg(self) -> Ticks {
+        Ticks(-self.0)
+    }
+}
+
+/// The basic, undotted note values, independent of tempo.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BasicLength {
+    Whole,
+    Half,
+    Quarter,
+    Eighth,
+    Sixteenth,
+    ThirtySecond,
+    SixtyFourth,
+}
+
+impl BasicLength {
+    /// Converts to its plain (undotted, non-tuplet) duration in ticks.
+    pub fn to_ticks(self) -> Ticks {
+        let whole = Ticks::RESOLUTION;
+
+        match self {
+            BasicLength::Whole => Ticks(whole),
+            BasicLength::Half => Ticks(whole / 2),
+            BasicLength::Quarter => Ticks(whole / 4),
+            BasicLength::Eighth => Ticks(whole / 8),
+            BasicLength::Sixteenth => Ticks(whole / 16),
+            BasicLength::ThirtySecond => Ticks(whole / 32),
+            BasicLength::SixtyFourth => Ticks(whole / 64),
+        }
+    }
+
+    /// The duration lengthened by half its own value, as in standard dotted-note notation.
+    pub fn dotted(self) -> Ticks {
+        let base = self.to_ticks();
+
+        base + Ticks(base.0 / 2)
+    }
+
+    /// The duration scaled by `den/num`, as in a `num`-in-the-space-of-`den` tuplet
+    /// (e.g. `num = 3, den = 2` for a triplet, making each note two thirds as long).
+    pub fn tuplet(self, num: i64, den: i64) -> Ticks {
+        let base = self.to_ticks();
+
+        Ticks(base.0 * den / num)
+    }
+}
 
 //-----------------------------------------------
 //--------------------- SET ---------------------
 //-----------------------------------------------
 
 /// A struct representing a set of times.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Times must be unique.
 /// * Times must be in ascending order.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeSet {
-    pub times: Vec<f64>
+    pub times: Vec<Ticks>
 }
 
 /// A struct representing the differences between adjacent times in a time set.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Intervals must be positive.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeSetShape {
-    pub intervals: Vec<f64>
+    pub intervals: Vec<Ticks>
 }
 
 //-----------------------------------------------
@@ -35,51 +147,75 @@ pub struct TimeSetShape {
 //-----------------------------------------------
 
 /// A struct representing a set of time classes.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Time classes must be in ascending order.
 /// * Time classes & modulus must be non-negative.
 /// * Time classes must be less than the modulus.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeClassSet {
-    pub time_classes: Vec<f64>,
-    pub modulus: f64
+    pub time_classes: Vec<Ticks>,
+    pub modulus: Ticks
 }
 
 /// A struct representing a patterned mapping from indices to times.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Harmonics must be positive, unique, and in ascending order.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeScaleMap {
-    pub harmonics: Vec<f64>,
-    pub offset: f64
+    pub harmonics: Vec<Ticks>,
+    pub offset: Ticks
 }
 
 /// A struct representing an indexed time class set.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Time classes and modulus must be non-negative.
-/// * Time classes must be unique. 
-/// * Time classes must be less than the modulus. 
+/// * Time classes must be unique.
+/// * Time classes must be less than the modulus.
 /// * Time classes must be in cyclically ascending order.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeScaleKey {
-    pub time_classes: Vec<f64>,
-    pub modulus: f64
+    pub time_classes: Vec<Ticks>,
+    pub modulus: Ticks
 }
 
 /// A struct representing the shape of a scale.
-/// 
+///
 /// ## Predicates
-/// 
+///
 /// * Intervals must be positive.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct TimeScaleShape {
-    pub intervals: Vec<f64>
+    pub intervals: Vec<Ticks>
+}
+
+//-----------------------------------------------
+//-------------------- GROUP --------------------
+//-----------------------------------------------
+
+/// A single element of a `RhythmGroup`: a sounding note, a silent rest, or a nested sub-group.
+#[derive(PartialEq, Debug, Clone)]
+pub enum RhythmItem {
+    Note(Ticks),
+    Rest(Ticks),
+    Group(RhythmGroup)
+}
+
+/// A nested, repeatable rhythmic pattern: an ordered list of notes, rests, and sub-groups,
+/// played through in sequence `repeats` times.
+///
+/// ## Predicates
+///
+/// * `repeats` must be positive.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RhythmGroup {
+    pub items: Vec<RhythmItem>,
+    pub repeats: i64
 }
 
 //---------------------------------------------//
@@ -88,121 +224,157 @@ pub mod constructors {
     use super::*;
 
     impl TimeSet {
-        pub fn new(times: Vec<f64>) -> Self {
+        pub fn new(times: Vec<Ticks>) -> Self {
             let mut times = times.clone();
-            times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            times.sort();
             times.dedup();
-    
+
             Self { times }
         }
     }
 
     impl TimeSetShape {
-        pub fn new(intervals: Vec<f64>) -> Self {
+        pub fn new(intervals: Vec<Ticks>) -> Self {
             #[cfg(debug_assertions)]
             {
                 for &interval in intervals.iter() {
-                    assert!(interval > 0.0, "Intervals must be positive.");
+                    assert!(interval > Ticks(0), "Intervals must be positive.");
                 }
             }
-    
+
             Self { intervals }
         }
     }
 
     impl TimeClassSet {
-        pub fn new(time_classes: Vec<f64>, modulus: f64) -> Self {
+        pub fn new(time_classes: Vec<Ticks>, modulus: Ticks) -> Self {
             #[cfg(debug_assertions)]
             {
                 for &time_class in time_classes.iter() {
                     assert!(time_class < modulus, "Time classes in TimeClassSet must be less than the modulus.");
-                    assert!(floats_are_sorted(&time_classes), "Time classes in TimeClassSet must be in ascending order.");
-                    assert!(time_class >= 0.0, "Time classes in TimeClassSet must be non-negative.");
+                    assert!(collection_is_sorted(&time_classes), "Time classes in TimeClassSet must be in ascending order.");
+                    assert!(time_class >= Ticks(0), "Time classes in TimeClassSet must be non-negative.");
                 }
-                assert!(modulus >= 0.0, "Modulus of TimeClassSet must be non-negative.");
+                assert!(modulus >= Ticks(0), "Modulus of TimeClassSet must be non-negative.");
             }
-    
+
             Self { time_classes, modulus }
         }
     }
 
     impl TimeScaleMap {
-        pub fn new(harmonics: Vec<f64>, offset: f64) -> Self {
+        pub fn new(harmonics: Vec<Ticks>, offset: Ticks) -> Self {
             #[cfg(debug_assertions)]
             {
                 for &harmonic in harmonics.iter() {
-                    assert!(harmonic > 0.0, "Harmonics in TimeScaleMap must be positive.");
+                    assert!(harmonic > Ticks(0), "Harmonics in TimeScaleMap must be positive.");
                 }
-                assert!(floats_are_unique(&harmonics), "Harmonics in TimeScaleMap must be unique.");
-                assert!(floats_are_sorted(&harmonics), "Harmonics in TimeScaleMap must be in order.");
+                assert!(collection_is_unique(&harmonics), "Harmonics in TimeScaleMap must be unique.");
+                assert!(collection_is_sorted(&harmonics), "Harmonics in TimeScaleMap must be in order.");
             }
-    
+
             Self { harmonics, offset }
         }
     }
 
     impl TimeScaleKey {
-        pub fn new(time_classes: Vec<f64>, modulus: f64, root: f64) -> Self {
+        pub fn new(time_classes: Vec<Ticks>, modulus: Ticks, root: Ticks) -> Self {
             #[cfg(debug_assertions)]
             {
                 for &time_class in time_classes.iter() {
                     assert!(time_class < modulus, "Time classes in TimeScaleKey must be less than the modulus.");
-                    assert!(time_class >= 0.0, "Time classes in TimeScaleKey must be non-negative.");
+                    assert!(time_class >= Ticks(0), "Time classes in TimeScaleKey must be non-negative.");
                 }
                 assert!(time_classes.contains(&root), "Time classes in TimeScaleKey must contain root.");
-                assert!(modulus >= 0.0, "Modulus of TimeScaleKey must be non-negative.");
+                assert!(modulus >= Ticks(0), "Modulus of TimeScaleKey must be non-negative.");
             }
-    
-            let time_classes = cyclically_order_floats(&time_classes, root);
-    
+
+            let time_classes = cyclically_order_vector(&time_classes, root);
+
             Self { time_classes, modulus }
         }
     }
 
     impl TimeScaleShape {
-        pub fn new(intervals: Vec<f64>) -> Self {
+        pub fn new(intervals: Vec<Ticks>) -> Self {
             #[cfg(debug_assertions)]
             {
                 for &interval in intervals.iter() {
-                    assert!(interval > 0.0, "Intervals in TimeScaleShape must be positive.");
+                    assert!(interval > Ticks(0), "Intervals in TimeScaleShape must be positive.");
                 }
             }
             Self { intervals }
         }
     }
+
+    impl RhythmGroup {
+        pub fn new(items: Vec<RhythmItem>, repeats: i64) -> Self {
+            #[cfg(debug_assertions)]
+            {
+                assert!(repeats > 0, "Repeats in RhythmGroup must be positive.");
+            }
+
+            Self { items, repeats }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_basic_length_to_ticks() {
+        assert_eq!(BasicLength::Whole.to_ticks(), Ticks(192));
+        assert_eq!(BasicLength::Quarter.to_ticks(), Ticks(48));
+        assert_eq!(BasicLength::Sixteenth.to_ticks(), Ticks(12));
+    }
+
+    #[test]
+    fn test_basic_length_dotted() {
+        assert_eq!(BasicLength::Quarter.dotted(), Ticks(72));
+    }
+
+    #[test]
+    fn test_basic_length_tuplet() {
+        assert_eq!(BasicLength::Quarter.tuplet(3, 2), Ticks(32));
+    }
+
+    #[test]
+    fn test_ticks_seconds_round_trip() {
+        let ticks = BasicLength::Quarter.to_ticks();
+
+        assert_eq!(ticks.to_seconds(120.0), 0.5);
+        assert_eq!(Ticks::from_seconds(0.5, 120.0), ticks);
+    }
+
     #[test]
     #[should_panic]
     fn test_time_shape() {
-        let time_set_shape = TimeSetShape::new(vec![-1.0, 0.0, 1.2]);
+        let time_set_shape = TimeSetShape::new(vec![Ticks(-12), Ticks(0), Ticks(144)]);
     }
 
     #[test]
     #[should_panic]
     fn test_time_class_set() {
-        let time_class_set = TimeClassSet::new(vec![-1.0, 0.1, 3.2], 2.4);
+        let time_class_set = TimeClassSet::new(vec![Ticks(-12), Ticks(12), Ticks(384)], Ticks(288));
     }
 
     #[test]
     #[should_panic]
     fn test_time_scale_map() {
-        let time_scale_map = TimeScaleMap::new(vec![-1.2, 3.2, 1.32], 1.2);
+        let time_scale_map = TimeScaleMap::new(vec![Ticks(-144), Ticks(384), Ticks(158)], Ticks(144));
     }
 
     #[test]
     #[should_panic]
     fn test_time_scale_key() {
-        let time_scale_key = TimeScaleKey::new(vec![1.2, 3.3, 4.5], 6.0, 2.2);
+        let time_scale_key = TimeScaleKey::new(vec![Ticks(144), Ticks(396), Ticks(540)], Ticks(720), Ticks(264));
     }
 
     #[test]
     #[should_panic]
     fn test_time_scale_shape() {
-        let time_scale_shape = TimeScaleShape::new(vec![-1.0, 1.4, 0.23, 0.11]);
+        let time_scale_shape = TimeScaleShape::new(vec![Ticks(-120), Ticks(168), Ticks(28), Ticks(13)]);
     }
-}
\ No newline at end of file
+}
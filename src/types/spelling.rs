@@ -0,0 +1,176 @@
+use crate::types::scale::*;
+use crate::types::melody::*;
+use std::fmt;
+
+/// A letter in the diatonic musical alphabet.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Letter {
+    C, D, E, F, G, A, B
+}
+
+/// A human-readable note name: a letter plus an accidental offset in semitones.
+///
+/// Only meaningful in a modulus-12 context; for other moduli, pitch classes
+/// are rendered numerically instead.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct NoteName {
+    pub letter: Letter,
+    pub accidental: i16
+}
+
+/// The twelve pitch classes spelled with sharps, in pitch-class order starting at C.
+const SHARP_NAMES: [(Letter, i16); 12] = [
+    (Letter::C, 0), (Letter::C, 1), (Letter::D, 0), (Letter::D, 1),
+    (Letter::E, 0), (Letter::F, 0), (Letter::F, 1), (Letter::G, 0),
+    (Letter::G, 1), (Letter::A, 0), (Letter::A, 1), (Letter::B, 0)
+];
+
+/// The twelve pitch classes spelled with flats, in pitch-class order starting at C.
+const FLAT_NAMES: [(Letter, i16); 12] = [
+    (Letter::C, 0), (Letter::D, -1), (Letter::D, 0), (Letter::E, -1),
+    (Letter::E, 0), (Letter::F, 0), (Letter::G, -1), (Letter::G, 0),
+    (Letter::A, -1), (Letter::A, 0), (Letter::B, -1), (Letter::B, 0)
+];
+
+/// Root pitch classes (mod 12) of the keys that are conventionally spelled with flats.
+const FLAT_KEY_ROOTS: [i16; 6] = [1, 3, 5, 6, 8, 10];
+
+impl NoteName {
+    /// Spells a pitch class (mod 12) as a `NoteName`, choosing sharps or flats.
+    pub fn from_pitch_class(pitch_class: i16, use_flats: bool) -> Self {
+        let table = if use_flats { &FLAT_NAMES } else { &SHARP_NAMES };
+        let (letter, accidental) = table[pitch_class.rem_euclid(12) as usize];
+
+        Self { letter, accidental }
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Letter::C => "C", Letter::D => "D", Letter::E => "E", Letter::F => "F",
+            Letter::G => "G", Letter::A => "A", Letter::B => "B"
+        };
+
+        write!(f, "{}", letter)
+    }
+}
+
+impl fmt::Display for NoteName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let accidental = if self.accidental > 0 {
+            "#".repeat(self.accidental as usize)
+        } else {
+            "b".repeat((-self.accidental) as usize)
+        };
+
+        write!(f, "{}{}", self.letter, accidental)
+    }
+}
+
+/// Reports whether a key rooted at `root` (mod 12) is conventionally spelled with flats.
+fn prefers_flats(root: i16) -> bool {
+    FLAT_KEY_ROOTS.contains(&root.rem_euclid(12))
+}
+
+impl Scale {
+    /// Spells the scale's pitch classes as note names, choosing sharps or flats from `key`.
+    pub fn spell(&self, key: &ScaleKey) -> Vec<NoteName> {
+        let use_flats = prefers_flats(key.pitch_classes[0]);
+        self.pitch_classes.iter().map(|&pc| NoteName::from_pitch_class(pc, use_flats)).collect()
+    }
+}
+
+impl ScaleKey {
+    /// Spells the key's pitch classes as note names, choosing sharps or flats from its own root.
+    pub fn spell(&self) -> Vec<NoteName> {
+        let use_flats = prefers_flats(self.pitch_classes[0]);
+        self.pitch_classes.iter().map(|&pc| NoteName::from_pitch_class(pc, use_flats)).collect()
+    }
+}
+
+impl Melody {
+    /// Spells the melody's absolute pitches as note names plus octave numbers, relative to `key`.
+    ///
+    /// The octave number is derived from `pitch.div_euclid(12)`. Only meaningful for modulus 12.
+    pub fn spell(&self, key: &ScaleKey) -> Vec<String> {
+        let use_flats = prefers_flats(key.pitch_classes[0]);
+
+        self.pitches.iter().map(|&pitch| {
+            let octave = pitch.div_euclid(12);
+            let name = NoteName::from_pitch_class(pitch.rem_euclid(12), use_flats);
+            format!("{}{}", name, octave)
+        }).collect()
+    }
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modulus == 12 {
+            let names: Vec<String> = self.pitch_classes.iter()
+                .map(|&pc| NoteName::from_pitch_class(pc, false).to_string())
+                .collect();
+
+            write!(f, "{}", names.join(" "))
+        } else {
+            write!(f, "{:?} mod {}", self.pitch_classes, self.modulus)
+        }
+    }
+}
+
+impl fmt::Display for ScaleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modulus == 12 {
+            let names: Vec<String> = self.spell().iter().map(|name| name.to_string()).collect();
+
+            write!(f, "{}", names.join(" "))
+        } else {
+            write!(f, "{:?} mod {}", self.pitch_classes, self.modulus)
+        }
+    }
+}
+
+impl fmt::Display for Melody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.pitches.iter().map(|&pitch| {
+            let octave = pitch.div_euclid(12);
+            let name = NoteName::from_pitch_class(pitch.rem_euclid(12), false);
+            format!("{}{}", name, octave)
+        }).collect();
+
+        write!(f, "{}", names.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_name_sharp() {
+        let name = NoteName::from_pitch_class(6, false);
+
+        assert_eq!(name.to_string(), "F#");
+    }
+
+    #[test]
+    fn test_note_name_flat() {
+        let name = NoteName::from_pitch_class(6, true);
+
+        assert_eq!(name.to_string(), "Gb");
+    }
+
+    #[test]
+    fn test_scale_key_display() {
+        let scale_key = ScaleKey::new(vec![0,2,4,5,7,9,11], 12, 0);
+
+        assert_eq!(scale_key.to_string(), "C D E F G A B");
+    }
+
+    #[test]
+    fn test_melody_display() {
+        let melody = Melody::new(vec![0, 16]);
+
+        assert_eq!(melody.to_string(), "C0 E1");
+    }
+}
@@ -42,13 +42,6 @@ pub fn cyclically_order_vector<T: Ord + std::clone::Clone>(slice: &[T], start: T
     cyclically_ordered
 }
 
-pub fn cyclically_order_floats(floats: &[f64], start: f64) -> Vec<f64>{
-    let start_index = floats.iter().position(|x| *x == start).unwrap();
-    let mut cyclically_ordered: Vec<f64> = floats.to_vec();
-    cyclically_ordered.rotate_left(start_index);
-    cyclically_ordered
-}
-
 pub fn find_aperiodic_substring<T: PartialEq + Clone>(sequence: &[T]) -> Vec<T> {
     let sequence_len = sequence.len();
 
@@ -97,14 +90,3 @@ pub fn collection_is_cyclically_ascending<T: Ord>(collection: &[T]) -> bool {
         || collection.last() <= collection.first()
 }
 
-pub fn floats_are_unique(floats: &[f64]) -> bool {
-    let mut unique_floats = floats.to_vec();
-    unique_floats.dedup();
-    floats.len() == unique_floats.len()
-}
-
-pub fn floats_are_sorted(floats: &[f64]) -> bool {
-    let mut sorted_floats = floats.to_vec();
-    sorted_floats.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    floats == sorted_floats        
-}